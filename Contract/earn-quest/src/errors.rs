@@ -30,4 +30,6 @@ pub enum Error {
     InvalidAdmin = 24,
     UnauthorizedUpgrade = 25,
     InvalidVersionNumber = 26,
+    InsufficientBalance = 27,
+    TransferFailed = 28,
 }
@@ -12,7 +12,7 @@ mod submission;
 pub mod test;
 pub mod types;
 
-use errors::Error;
+pub use errors::Error;
 use init::ContractConfig;
 use types::{Quest, Submission, UserStats};
 
@@ -98,18 +98,13 @@ impl EarnQuestContract {
         // Approve submission and increment claim counter
         submission::approve_submission(&env, &quest_id, &submitter, &verifier)?;
 
-        // Get submission
+        // Get quest and submission
+        let quest = storage::get_quest(&env, &quest_id).ok_or(Error::QuestNotFound)?;
         let mut sub = storage::get_submission(&env, &quest_id, &submitter)
             .ok_or(Error::SubmissionNotFound)?;
 
-        // Transfer reward using Stellar token contract
-        // Note: In production, this would transfer tokens. In tests, we skip this.
-        // let token_client = token::Client::new(&env, &quest.reward_asset);
-        // token_client.transfer(
-        //     &quest.creator,
-        //     &submitter,
-        //     &quest.reward_amount,
-        // );
+        // Transfer reward from the contract balance using the Stellar token contract
+        payout::transfer_reward(&env, &quest.reward_asset, &submitter, quest.reward_amount)?;
 
         // Award XP to user
         reputation::award_xp(&env, &submitter, 100)?;
@@ -1 +1,34 @@
+use soroban_sdk::{token, Address, Env};
 
+use crate::errors::Error;
+
+/// Transfer a reward from the contract balance to a participant
+///
+/// The contract must hold enough of `reward_asset` to cover the payout.
+/// Creators fund the contract by transferring reward tokens to its address.
+pub fn transfer_reward(
+    env: &Env,
+    reward_asset: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    // Validate amount
+    if amount <= 0 {
+        return Err(Error::InvalidRewardAmount);
+    }
+
+    let token_client = token::Client::new(env, reward_asset);
+    let contract_address = env.current_contract_address();
+
+    // Check contract balance
+    let balance = token_client.balance(&contract_address);
+    if balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    // Transfer from contract (the contract authorizes as owner of the funds)
+    match token_client.try_transfer(&contract_address, to, &amount) {
+        Ok(Ok(_)) => Ok(()),
+        _ => Err(Error::TransferFailed),
+    }
+}
@@ -1,9 +1,21 @@
 #[cfg(test)]
 mod tests {
-    use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Env};
+    use soroban_sdk::{
+        symbol_short, testutils::Address as _, token::StellarAssetClient, Address, BytesN, Env,
+    };
 
     use crate::{types::QuestStatus, EarnQuestContract, EarnQuestContractClient};
 
+    /// Register a reward token and fund the contract so approvals can pay out
+    fn create_funded_reward_asset(env: &Env, contract_id: &Address) -> Address {
+        let token_admin = Address::generate(env);
+        let reward_asset = env
+            .register_stellar_asset_contract_v2(token_admin)
+            .address();
+        StellarAssetClient::new(env, &reward_asset).mint(contract_id, &1_000_000);
+        reward_asset
+    }
+
     #[test]
     fn test_register_quest_with_participant_limit() {
         let env = Env::default();
@@ -69,7 +81,7 @@ mod tests {
 
         let creator = Address::generate(&env);
         let verifier = Address::generate(&env);
-        let reward_asset = Address::generate(&env);
+        let reward_asset = create_funded_reward_asset(&env, &contract_id);
 
         // Register quest with max_participants = 2
         client.register_quest(
@@ -109,7 +121,7 @@ mod tests {
 
         let creator = Address::generate(&env);
         let verifier = Address::generate(&env);
-        let reward_asset = Address::generate(&env);
+        let reward_asset = create_funded_reward_asset(&env, &contract_id);
 
         // Register quest with max_participants = 1
         client.register_quest(
@@ -145,7 +157,7 @@ mod tests {
 
         let creator = Address::generate(&env);
         let verifier = Address::generate(&env);
-        let reward_asset = Address::generate(&env);
+        let reward_asset = create_funded_reward_asset(&env, &contract_id);
 
         // Register quest
         client.register_quest(
@@ -185,7 +197,7 @@ mod tests {
 
         let creator = Address::generate(&env);
         let verifier = Address::generate(&env);
-        let reward_asset = Address::generate(&env);
+        let reward_asset = create_funded_reward_asset(&env, &contract_id);
         let submitter = Address::generate(&env);
 
         // Register quest
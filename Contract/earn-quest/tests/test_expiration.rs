@@ -4,6 +4,7 @@ use earn_quest::{EarnQuestContract, EarnQuestContractClient, QuestStatus};
 use soroban_sdk::{
     symbol_short,
     testutils::{Address as _, Ledger},
+    token::StellarAssetClient,
     Address, BytesN, Env,
 };
 
@@ -13,7 +14,12 @@ fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, A
 
     let creator = Address::generate(env);
     let verifier = Address::generate(env);
-    let reward_asset = Address::generate(env);
+    // Reward token with enough balance on the contract to pay out approvals
+    let token_admin = Address::generate(env);
+    let reward_asset = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    StellarAssetClient::new(env, &reward_asset).mint(&contract_id, &1_000_000);
 
     (client, creator, verifier, reward_asset)
 }
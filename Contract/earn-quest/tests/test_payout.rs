@@ -1 +1,188 @@
+#![cfg(test)]
 
+use earn_quest::{EarnQuestContract, EarnQuestContractClient, Error, SubmissionStatus};
+use soroban_sdk::{
+    symbol_short,
+    testutils::Address as _,
+    token::{StellarAssetClient, TokenClient},
+    Address, BytesN, Env,
+};
+
+fn setup_env<'a>(
+    env: &Env,
+) -> (
+    EarnQuestContractClient<'a>,
+    Address,
+    Address,
+    Address,
+    StellarAssetClient<'a>,
+    TokenClient<'a>,
+) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+
+    // Stellar Asset Contract used as the reward token
+    let token_admin = Address::generate(env);
+    let reward_asset = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_admin_client = StellarAssetClient::new(env, &reward_asset);
+    let token_client = TokenClient::new(env, &reward_asset);
+
+    (
+        client,
+        creator,
+        verifier,
+        reward_asset,
+        token_admin_client,
+        token_client,
+    )
+}
+
+#[test]
+fn test_approval_transfers_reward() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset, token_admin_client, token_client) =
+        setup_env(&env);
+    token_admin_client.mint(&client.address, &5000);
+
+    let quest_id = symbol_short!("pay1");
+    let deadline = env.ledger().timestamp() + 86400;
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &deadline,
+        &5,
+    );
+
+    let submitter = Address::generate(&env);
+    let proof_hash = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&quest_id, &submitter, &proof_hash);
+
+    assert_eq!(token_client.balance(&submitter), 0);
+
+    client.approve_submission(&quest_id, &submitter, &verifier);
+
+    // Reward moved from the contract to the submitter
+    assert_eq!(token_client.balance(&submitter), 1000);
+    assert_eq!(token_client.balance(&client.address), 4000);
+
+    let submission = client.get_submission(&quest_id, &submitter);
+    assert_eq!(submission.status, SubmissionStatus::Paid);
+}
+
+#[test]
+fn test_approval_fails_with_insufficient_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset, token_admin_client, token_client) =
+        setup_env(&env);
+    // Not enough to cover a single reward
+    token_admin_client.mint(&client.address, &999);
+
+    let quest_id = symbol_short!("pay2");
+    let deadline = env.ledger().timestamp() + 86400;
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &deadline,
+        &5,
+    );
+
+    let submitter = Address::generate(&env);
+    let proof_hash = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&quest_id, &submitter, &proof_hash);
+
+    let result = client.try_approve_submission(&quest_id, &submitter, &verifier);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+
+    // Nothing was paid and the submission was not marked Paid
+    assert_eq!(token_client.balance(&submitter), 0);
+    assert_eq!(token_client.balance(&client.address), 999);
+    let submission = client.get_submission(&quest_id, &submitter);
+    assert_eq!(submission.status, SubmissionStatus::Pending);
+
+    let quest = client.get_quest(&quest_id);
+    assert_eq!(quest.total_claims, 0);
+}
+
+#[test]
+fn test_approval_fails_without_funding() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset, _token_admin_client, _token_client) =
+        setup_env(&env);
+
+    let quest_id = symbol_short!("pay3");
+    let deadline = env.ledger().timestamp() + 86400;
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &deadline,
+        &5,
+    );
+
+    let submitter = Address::generate(&env);
+    let proof_hash = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&quest_id, &submitter, &proof_hash);
+
+    let result = client.try_approve_submission(&quest_id, &submitter, &verifier);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+}
+
+#[test]
+fn test_multiple_payouts_drain_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, creator, verifier, reward_asset, token_admin_client, token_client) =
+        setup_env(&env);
+    token_admin_client.mint(&client.address, &2000);
+
+    let quest_id = symbol_short!("pay4");
+    let deadline = env.ledger().timestamp() + 86400;
+    client.register_quest(
+        &quest_id,
+        &creator,
+        &reward_asset,
+        &1000_i128,
+        &verifier,
+        &deadline,
+        &5,
+    );
+
+    let submitters = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for (i, submitter) in submitters.iter().enumerate() {
+        let proof_hash = BytesN::from_array(&env, &[i as u8; 32]);
+        client.submit_proof(&quest_id, submitter, &proof_hash);
+    }
+
+    client.approve_submission(&quest_id, &submitters[0], &verifier);
+    client.approve_submission(&quest_id, &submitters[1], &verifier);
+    assert_eq!(token_client.balance(&client.address), 0);
+
+    // Third approval cannot be paid
+    let result = client.try_approve_submission(&quest_id, &submitters[2], &verifier);
+    assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+    assert_eq!(token_client.balance(&submitters[2]), 0);
+}
@@ -1,7 +1,9 @@
 #![cfg(test)]
 
 use earn_quest::{EarnQuestContract, EarnQuestContractClient, SubmissionStatus};
-use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Env};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, token::StellarAssetClient, Address, BytesN, Env,
+};
 
 fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, Address) {
     let contract_id = env.register_contract(None, EarnQuestContract);
@@ -9,7 +11,12 @@ fn setup_env<'a>(env: &Env) -> (EarnQuestContractClient<'a>, Address, Address, A
 
     let creator = Address::generate(env);
     let verifier = Address::generate(env);
-    let reward_asset = Address::generate(env);
+    // Reward token with enough balance on the contract to pay out approvals
+    let token_admin = Address::generate(env);
+    let reward_asset = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    StellarAssetClient::new(env, &reward_asset).mint(&contract_id, &1_000_000);
 
     (client, creator, verifier, reward_asset)
 }
@@ -1,5 +1,8 @@
 use crate::errors::Error;
+use crate::events;
 use crate::storage;
+use crate::types::AssetConfig;
+use crate::validation;
 use soroban_sdk::{Address, Env};
 
 /// Add a new admin (only callable by existing admin)
@@ -23,6 +26,8 @@ pub fn remove_admin(env: &Env, caller: &Address, admin_to_remove: &Address) -> R
     }
 
     storage::remove_admin(env, admin_to_remove);
+    // Approvals only count while their admin stays in office
+    storage::remove_withdraw_override_approval(env, admin_to_remove);
     Ok(())
 }
 
@@ -41,3 +46,65 @@ pub fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
 
     Ok(())
 }
+
+/// Set the platform fee deducted from payouts and its treasury (admin only)
+pub fn set_platform_fee(
+    env: &Env,
+    caller: &Address,
+    fee_bps: u32,
+    treasury: &Address,
+) -> Result<(), Error> {
+    require_admin(env, caller)?;
+    validation::validate_fee_bps(fee_bps)?;
+
+    let old_fee_bps = storage::get_fee_bps(env);
+    storage::set_platform_fee(env, fee_bps, treasury);
+
+    events::platform_fee_set(env, caller.clone(), old_fee_bps, fee_bps, treasury.clone());
+    Ok(())
+}
+
+/// Allowlist a reward asset or update its per-claim reward bounds (admin only)
+pub fn allow_asset(
+    env: &Env,
+    caller: &Address,
+    asset: &Address,
+    min_reward: i128,
+    max_reward: i128,
+) -> Result<(), Error> {
+    require_admin(env, caller)?;
+
+    let config = AssetConfig {
+        min_reward,
+        max_reward,
+    };
+    validation::validate_asset_config(&config)?;
+    storage::set_asset_config(env, asset, &config);
+
+    events::asset_allowed(env, asset.clone(), min_reward, max_reward);
+    Ok(())
+}
+
+/// Remove a reward asset from the allowlist (admin only)
+pub fn remove_asset(env: &Env, caller: &Address, asset: &Address) -> Result<(), Error> {
+    require_admin(env, caller)?;
+    storage::remove_asset_config(env, asset)?;
+
+    events::asset_removed(env, asset.clone());
+    Ok(())
+}
+
+/// Allow an issuer to create mint-on-claim quests for an allowlisted asset (admin only)
+///
+/// The contract must separately be made the token's admin for mints to succeed.
+pub fn set_mintable_asset(
+    env: &Env,
+    caller: &Address,
+    asset: &Address,
+    issuer: &Address,
+) -> Result<(), Error> {
+    require_admin(env, caller)?;
+    storage::get_asset_config(env, asset)?;
+    storage::set_asset_issuer(env, asset, issuer);
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use crate::errors::Error;
+use crate::events;
+use crate::quest;
+use crate::storage;
+use crate::validation;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Symbol, Vec};
+
+/// Set, rotate or remove the Merkle root of a quest's allowlist (creator only).
+///
+/// Leaves are the SHA-256 of each listed address's XDR encoding; inner
+/// nodes hash the two children in ascending order, so proofs carry no
+/// left/right flags. Rotating the root does not affect existing submissions.
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is Active or Paused
+pub fn set_allowlist_root(
+    env: &Env,
+    id: &Symbol,
+    creator: &Address,
+    root: Option<BytesN<32>>,
+) -> Result<(), Error> {
+    let quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    quest::validate_quest_is_live(&quest.status)?;
+
+    match &root {
+        Some(root) => storage::set_allowlist_root(env, id, root),
+        None => storage::remove_allowlist_root(env, id),
+    }
+
+    events::allowlist_root_set(env, id.clone(), root);
+
+    Ok(())
+}
+
+/// Ensure a submitter is on a quest's allowlist, if it has one.
+///
+/// # Returns
+/// * `Ok(())` if the quest is open or `proof` leads from the submitter's
+///   leaf to the root
+/// * `Err(Error::ArrayTooLong)` if the proof is deeper than MAX_MERKLE_DEPTH
+/// * `Err(Error::Unauthorized)` if the submitter is not listed
+pub fn require_listed(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    proof: &Vec<BytesN<32>>,
+) -> Result<(), Error> {
+    let root = match storage::get_allowlist_root(env, quest_id) {
+        Some(root) => root,
+        None => return Ok(()),
+    };
+
+    if proof.len() > validation::MAX_MERKLE_DEPTH {
+        return Err(Error::ArrayTooLong);
+    }
+
+    let mut node = env.crypto().sha256(&submitter.clone().to_xdr(env)).to_bytes();
+    for sibling in proof.iter() {
+        let (first, second) = if node <= sibling { (node, sibling) } else { (sibling, node) };
+        let mut pair = Bytes::from(first);
+        pair.append(&Bytes::from(second));
+        node = env.crypto().sha256(&pair).to_bytes();
+    }
+
+    if node != root {
+        return Err(Error::Unauthorized);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use crate::errors::Error;
+use crate::quest;
+use crate::storage;
+use crate::types::{Quest, QuestNode};
+use crate::validation;
+use soroban_sdk::{vec, Address, Env, Symbol, Vec};
+
+/// Require hunters to complete other quests before submitting to this one
+/// (creator only). An empty list removes the requirement.
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is Active or Paused
+/// - Nobody has submitted proof yet
+/// - At most MAX_PREREQUISITES prerequisites, each an existing quest
+/// - The quest does not end up requiring itself, directly or through its
+///   chain, which would make it impossible to unlock
+/// - The resulting chain has at most MAX_QUEST_CHAIN quests
+pub fn set_prerequisites(
+    env: &Env,
+    id: &Symbol,
+    creator: &Address,
+    prerequisites: Vec<Symbol>,
+) -> Result<(), Error> {
+    let mut quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    quest::validate_quest_is_live(&quest.status)?;
+
+    if storage::has_submitters(env, id) {
+        return Err(Error::QuestTermsLocked);
+    }
+
+    if prerequisites.len() > validation::MAX_PREREQUISITES {
+        return Err(Error::ArrayTooLong);
+    }
+
+    quest.prerequisites = prerequisites;
+    collect_chain(env, &quest, false)?;
+    storage::set_quest(env, id, &quest);
+
+    Ok(())
+}
+
+/// Ensure a submitter has been paid for every prerequisite of a quest.
+///
+/// Completions are recorded for everyone a completing claim paid, so team
+/// members and earlier attempts of a repeatable prerequisite count as well.
+pub fn require_prerequisites(env: &Env, quest: &Quest, submitter: &Address) -> Result<(), Error> {
+    for prerequisite in quest.prerequisites.iter() {
+        if !storage::has_completed_quest(env, &prerequisite, submitter) {
+            return Err(Error::PrerequisiteNotMet);
+        }
+    }
+    Ok(())
+}
+
+/// The quest followed by everything it depends on, transitively, each with
+/// its direct prerequisites; quests shared by several branches appear once.
+///
+/// Editing a prerequisite can grow the chains of the quests that depend on
+/// it past MAX_QUEST_CHAIN, so the view stops at that many quests.
+pub fn quest_chain(env: &Env, id: &Symbol) -> Result<Vec<QuestNode>, Error> {
+    let quest = storage::get_quest(env, id)?;
+    collect_chain(env, &quest, true)
+}
+
+/// Walk the prerequisites of `root` breadth-first, stopping at
+/// MAX_QUEST_CHAIN quests if `truncate` is set, or failing otherwise.
+///
+/// Stored quests never form a cycle, so any cycle goes through `root`.
+fn collect_chain(env: &Env, root: &Quest, truncate: bool) -> Result<Vec<QuestNode>, Error> {
+    let mut nodes = vec![
+        env,
+        QuestNode {
+            id: root.id.clone(),
+            prerequisites: root.prerequisites.clone(),
+        },
+    ];
+
+    let mut next = 0;
+    while next < nodes.len() {
+        let node = nodes.get_unchecked(next);
+        for prerequisite in node.prerequisites.iter() {
+            if prerequisite == root.id {
+                return Err(Error::PrerequisiteCycle);
+            }
+            if nodes.iter().any(|seen| seen.id == prerequisite) {
+                continue;
+            }
+            if nodes.len() >= validation::MAX_QUEST_CHAIN {
+                if truncate {
+                    return Ok(nodes);
+                }
+                return Err(Error::ArrayTooLong);
+            }
+            let quest = storage::get_quest(env, &prerequisite)?;
+            nodes.push_back(QuestNode {
+                id: quest.id,
+                prerequisites: quest.prerequisites,
+            });
+        }
+        next += 1;
+    }
+
+    Ok(nodes)
+}
@@ -0,0 +1,57 @@
+use crate::errors::Error;
+use crate::quest;
+use crate::storage;
+use crate::types::HoldingRule;
+use crate::validation;
+use soroban_sdk::{token, Address, Env, Symbol, Vec};
+
+/// Reserve a quest for holders of sponsor tokens (creator only).
+///
+/// A hunter must hold at least `min_balance` of every rule's token when
+/// submitting proof; an empty list opens the quest to everyone again.
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is Active or Paused
+/// - Nobody has submitted proof yet
+/// - At most MAX_HOLDING_RULES rules, each with a positive minimum balance
+pub fn set_holding_rules(
+    env: &Env,
+    id: &Symbol,
+    creator: &Address,
+    rules: Vec<HoldingRule>,
+) -> Result<(), Error> {
+    let quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    quest::validate_quest_is_live(&quest.status)?;
+
+    if storage::has_submitters(env, id) {
+        return Err(Error::QuestTermsLocked);
+    }
+
+    if rules.len() > validation::MAX_HOLDING_RULES {
+        return Err(Error::ArrayTooLong);
+    }
+    if rules.iter().any(|rule| rule.min_balance <= 0) {
+        return Err(Error::InvalidRewardAmount);
+    }
+
+    storage::set_holding_rules(env, id, &rules);
+
+    Ok(())
+}
+
+/// Ensure a submitter holds the minimum balance of every token a quest requires.
+pub fn require_holdings(env: &Env, quest_id: &Symbol, submitter: &Address) -> Result<(), Error> {
+    for rule in storage::get_holding_rules(env, quest_id).iter() {
+        let balance = token::Client::new(env, &rule.token).balance(submitter);
+        if balance < rule.min_balance {
+            return Err(Error::NotEligible);
+        }
+    }
+    Ok(())
+}
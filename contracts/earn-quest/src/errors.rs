@@ -9,6 +9,10 @@ pub enum Error {
     QuestNotFound = 2,
     InvalidRewardAmount = 3,
     QuestStillActive = 4,
+    InvalidMaxClaims = 5,
+    QuestTermsLocked = 7,
+    WrongQuestKind = 8,
+    QuestFull = 9,
     
     // Auth Errors
     Unauthorized = 10,
@@ -16,20 +20,31 @@ pub enum Error {
     // Submission Errors
     InvalidSubmissionStatus = 20,
     SubmissionNotFound = 21,
+    PrerequisiteNotMet = 22,
+    NotEligible = 23,
+    PrerequisiteCycle = 24,
     
     // Payout Errors
     InsufficientBalance = 30,
     TransferFailed = 31,
     AlreadyClaimed = 32,
     InvalidAsset = 33,
+    AlreadyFunded = 34,
+    ExceedsSurplus = 35,
+    InvalidVestingSchedule = 36,
+    VestingNotFound = 37,
+    NothingToWithdraw = 38,
+    FeeTooHigh = 39,
     
     // Reputation Errors
     UserStatsNotFound = 40,
+    InvalidLevel = 41,
     // Security / Emergency
     Paused = 50,
     TimelockNotExpired = 51,
     AlreadyApproved = 52,
     InsufficientApprovals = 53,
+    PayoutsPaused = 54,
 
     // Validation Errors
     DeadlineInPast = 60,
@@ -40,4 +55,23 @@ pub enum Error {
     InvalidAddress = 65,
     QuestExpired = 66,
     QuestNotActive = 67,
+    DeadlineNotExtended = 68,
+
+    // Reward Pool Errors
+    PoolNotFound = 70,
+    PoolAlreadyExists = 71,
+    PoolExhausted = 72,
+
+    // Mint Errors
+    NotMintable = 80,
+    MintCapExceeded = 81,
+
+    // Contest Errors
+    NotRanked = 91,
+    DuplicateWinner = 92,
+
+    // Milestone and Team Errors
+    InvalidShares = 100,
+    MilestoneNotFound = 101,
+    MilestoneOutOfOrder = 102,
 }
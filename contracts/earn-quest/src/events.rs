@@ -1,11 +1,16 @@
 #![allow(unused)]
-use soroban_sdk::{Env, Symbol, Address, BytesN, symbol_short};
+use soroban_sdk::{Env, IntoVal, Symbol, Address, BytesN, Val, Vec, symbol_short};
 use crate::types::Badge;
 
 // Event Topics (Names)
 const TOPIC_QUEST_REGISTERED: Symbol = symbol_short!("quest_reg");
 const TOPIC_PROOF_SUBMITTED: Symbol = symbol_short!("proof_sub");
 const TOPIC_SUBMISSION_APPROVED: Symbol = symbol_short!("sub_appr");
+const TOPIC_SUBMISSION_REJECTED: Symbol = symbol_short!("sub_rej");
+const TOPIC_WINNER_SELECTED: Symbol = symbol_short!("bnty_win");
+const TOPIC_RANKING_FINALIZED: Symbol = symbol_short!("ranked");
+const TOPIC_MILESTONE_APPROVED: Symbol = symbol_short!("ms_appr");
+const TOPIC_ALLOWLIST_ROOT_SET: Symbol = symbol_short!("al_root");
 const TOPIC_REWARD_CLAIMED: Symbol = symbol_short!("claimed");
 const TOPIC_XP_AWARDED: Symbol = symbol_short!("xp_award");
 const TOPIC_LEVEL_UP: Symbol = symbol_short!("level_up");
@@ -15,6 +20,28 @@ const TOPIC_EMERGENCY_UNPAUSED: Symbol = symbol_short!("eunpause");
 const TOPIC_EMERGENCY_WITHDRAW: Symbol = symbol_short!("ewdraw");
 const TOPIC_UNPAUSE_APPROVED: Symbol = symbol_short!("uappr");
 const TOPIC_TIMELOCK_SCHEDULED: Symbol = symbol_short!("tl_sched");
+const TOPIC_WITHDRAW_OVERRIDE_APPROVED: Symbol = symbol_short!("wdovr_apr");
+const TOPIC_ESCROW_WRITTEN_OFF: Symbol = symbol_short!("esc_wroff");
+const TOPIC_QUEST_FUNDED: Symbol = symbol_short!("q_funded");
+const TOPIC_QUEST_CANCELLED: Symbol = symbol_short!("q_cancel");
+const TOPIC_QUEST_EXPIRED: Symbol = symbol_short!("q_expire");
+const TOPIC_QUEST_TOPPED_UP: Symbol = symbol_short!("q_topup");
+const TOPIC_QUEST_SPONSORED: Symbol = symbol_short!("q_sponsor");
+const TOPIC_SPONSOR_REFUNDED: Symbol = symbol_short!("spn_rfnd");
+const TOPIC_POOL_CREATED: Symbol = symbol_short!("pool_new");
+const TOPIC_POOL_DEPOSITED: Symbol = symbol_short!("pool_dep");
+const TOPIC_POOL_WITHDRAWN: Symbol = symbol_short!("pool_wdr");
+const TOPIC_POOL_QUEST_ATTACHED: Symbol = symbol_short!("pool_att");
+const TOPIC_POOL_CAP_SET: Symbol = symbol_short!("pool_cap");
+const TOPIC_REWARD_INCREASED: Symbol = symbol_short!("rwd_inc");
+const TOPIC_QUEST_AMENDED: Symbol = symbol_short!("q_amend");
+const TOPIC_VESTED_WITHDRAWN: Symbol = symbol_short!("vest_wdr");
+const TOPIC_PLATFORM_FEE_SET: Symbol = symbol_short!("fee_set");
+const TOPIC_ASSET_ALLOWED: Symbol = symbol_short!("asset_ok");
+const TOPIC_ASSET_REMOVED: Symbol = symbol_short!("asset_rm");
+const TOPIC_PAYOUT_LIMIT_SET: Symbol = symbol_short!("plimit");
+const TOPIC_CIRCUIT_TRIPPED: Symbol = symbol_short!("cb_trip");
+const TOPIC_PAYOUTS_RESUMED: Symbol = symbol_short!("cb_resume");
 
 /// Emit when a new quest is created
 pub fn quest_registered(
@@ -33,6 +60,174 @@ pub fn quest_registered(
     env.events().publish(topics, data);
 }
 
+/// Emit when a quest's reward budget is escrowed
+pub fn quest_funded(
+    env: &Env,
+    quest_id: Symbol,
+    funder: Address,
+    reward_asset: Address,
+    amount: i128,
+) {
+    // Topics: [EventName, QuestID, Funder]
+    let topics = (TOPIC_QUEST_FUNDED, quest_id, funder);
+    // Data: (Asset, Amount)
+    let data = (reward_asset, amount);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a creator cancels a quest
+pub fn quest_cancelled(
+    env: &Env,
+    quest_id: Symbol,
+    creator: Address,
+    reward_asset: Address,
+    refunded: i128,
+) {
+    // Topics: [EventName, QuestID, Creator]
+    let topics = (TOPIC_QUEST_CANCELLED, quest_id, creator);
+    // Data: (Asset, Refunded Amount)
+    let data = (reward_asset, refunded);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an expired quest is swept and its leftover escrow refunded
+pub fn quest_expired(
+    env: &Env,
+    quest_id: Symbol,
+    creator: Address,
+    reward_asset: Address,
+    refunded: i128,
+) {
+    // Topics: [EventName, QuestID, Creator]
+    let topics = (TOPIC_QUEST_EXPIRED, quest_id, creator);
+    // Data: (Asset, Refunded Amount)
+    let data = (reward_asset, refunded);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a creator deposits additional budget into a quest's escrow
+pub fn quest_topped_up(
+    env: &Env,
+    quest_id: Symbol,
+    creator: Address,
+    reward_asset: Address,
+    amount: i128,
+    escrow: i128,
+) {
+    // Topics: [EventName, QuestID, Creator]
+    let topics = (TOPIC_QUEST_TOPPED_UP, quest_id, creator);
+    // Data: (Asset, Deposited Amount, Escrow Balance)
+    let data = (reward_asset, amount, escrow);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a sponsor contributes to a quest's escrow
+pub fn quest_sponsored(
+    env: &Env,
+    quest_id: Symbol,
+    sponsor: Address,
+    reward_asset: Address,
+    amount: i128,
+    escrow: i128,
+) {
+    // Topics: [EventName, QuestID, Sponsor]
+    let topics = (TOPIC_QUEST_SPONSORED, quest_id, sponsor);
+    // Data: (Asset, Contributed Amount, Escrow Balance)
+    let data = (reward_asset, amount, escrow);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a sponsor receives its share of a quest refund
+pub fn sponsor_refunded(
+    env: &Env,
+    quest_id: Symbol,
+    sponsor: Address,
+    reward_asset: Address,
+    amount: i128,
+) {
+    // Topics: [EventName, QuestID, Sponsor]
+    let topics = (TOPIC_SPONSOR_REFUNDED, quest_id, sponsor);
+    // Data: (Asset, Refunded Amount)
+    let data = (reward_asset, amount);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a reward pool is created
+pub fn pool_created(env: &Env, pool_id: Symbol, owner: Address, asset: Address) {
+    // Topics: [EventName, PoolID, Owner]
+    let topics = (TOPIC_POOL_CREATED, pool_id, owner);
+    // Data: (Asset,)
+    let data = (asset,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when tokens are deposited into a reward pool
+pub fn pool_deposited(env: &Env, pool_id: Symbol, from: Address, amount: i128, balance: i128) {
+    // Topics: [EventName, PoolID, Depositor]
+    let topics = (TOPIC_POOL_DEPOSITED, pool_id, from);
+    // Data: (Deposited Amount, Pool Balance)
+    let data = (amount, balance);
+    env.events().publish(topics, data);
+}
+
+/// Emit when the owner withdraws tokens from a reward pool
+pub fn pool_withdrawn(env: &Env, pool_id: Symbol, owner: Address, amount: i128, balance: i128) {
+    // Topics: [EventName, PoolID, Owner]
+    let topics = (TOPIC_POOL_WITHDRAWN, pool_id, owner);
+    // Data: (Withdrawn Amount, Pool Balance)
+    let data = (amount, balance);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a quest is registered to draw from a reward pool
+pub fn pool_quest_attached(env: &Env, pool_id: Symbol, quest_id: Symbol, cap: i128) {
+    // Topics: [EventName, PoolID, QuestID]
+    let topics = (TOPIC_POOL_QUEST_ATTACHED, pool_id, quest_id);
+    // Data: (Cap,)
+    let data = (cap,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a pool owner changes how much a quest may draw from the pool
+pub fn pool_cap_set(env: &Env, pool_id: Symbol, quest_id: Symbol, previous_cap: i128, cap: i128) {
+    // Topics: [EventName, PoolID, QuestID]
+    let topics = (TOPIC_POOL_CAP_SET, pool_id, quest_id);
+    // Data: (Previous Cap, Cap)
+    let data = (previous_cap, cap);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a creator raises the per-claim reward of a quest
+pub fn reward_increased(
+    env: &Env,
+    quest_id: Symbol,
+    creator: Address,
+    old_amount: i128,
+    new_amount: i128,
+    deposited: i128,
+) {
+    // Topics: [EventName, QuestID, Creator]
+    let topics = (TOPIC_REWARD_INCREASED, quest_id, creator);
+    // Data: (Old Amount, New Amount, Deposited Amount)
+    let data = (old_amount, new_amount, deposited);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a creator amends a quest parameter
+pub fn quest_amended<T: IntoVal<Env, Val>>(
+    env: &Env,
+    quest_id: Symbol,
+    field: Symbol,
+    old_value: T,
+    new_value: T,
+) {
+    // Topics: [EventName, QuestID, Field]
+    let topics = (TOPIC_QUEST_AMENDED, quest_id, field);
+    // Data: (Old Value, New Value)
+    let data: (Val, Val) = (old_value.into_val(env), new_value.into_val(env));
+    env.events().publish(topics, data);
+}
+
 /// Emit when contract is paused by admin
 pub fn emergency_paused(env: &Env, by: Address) {
     let topics = (TOPIC_EMERGENCY_PAUSED, by.clone());
@@ -61,6 +256,70 @@ pub fn unpause_approved(env: &Env, admin: Address) {
     env.events().publish(topics, data);
 }
 
+/// Emit when an admin sets or clears an asset's payout limit
+pub fn payout_limit_set(env: &Env, by: Address, asset: Address, max_amount: i128, window_seconds: u64) {
+    let topics = (TOPIC_PAYOUT_LIMIT_SET, by);
+    let data = (asset, max_amount, window_seconds);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a claim trips the payout circuit breaker
+pub fn circuit_tripped(
+    env: &Env,
+    asset: Address,
+    attempted: i128,
+    window_total: i128,
+    max_amount: i128,
+) {
+    // Topics: [EventName, Asset]
+    let topics = (TOPIC_CIRCUIT_TRIPPED, asset);
+    // Data: (Attempted Amount, Paid In Window, Limit)
+    let data = (attempted, window_total, max_amount);
+    env.events().publish(topics, data);
+}
+
+/// Emit when the unpause approvals resume an asset's payouts
+pub fn payouts_resumed(env: &Env, by: Address, asset: Address) {
+    let topics = (TOPIC_PAYOUTS_RESUMED, by);
+    let data = (asset,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin approves an emergency withdrawal override
+pub fn withdraw_override_approved(env: &Env, admin: Address, asset: Address, to: Address, amount: i128) {
+    let topics = (TOPIC_WITHDRAW_OVERRIDE_APPROVED, admin);
+    let data = (asset, to, amount);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an override withdrawal writes off a quest's escrow
+pub fn escrow_written_off(env: &Env, quest_id: Symbol, asset: Address, amount: i128) {
+    let topics = (TOPIC_ESCROW_WRITTEN_OFF, quest_id);
+    let data = (asset, amount);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin changes the platform fee
+pub fn platform_fee_set(env: &Env, by: Address, old_fee_bps: u32, new_fee_bps: u32, treasury: Address) {
+    let topics = (TOPIC_PLATFORM_FEE_SET, by);
+    let data = (old_fee_bps, new_fee_bps, treasury);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin allowlists a reward asset or changes its bounds
+pub fn asset_allowed(env: &Env, asset: Address, min_reward: i128, max_reward: i128) {
+    let topics = (TOPIC_ASSET_ALLOWED, asset);
+    let data = (min_reward, max_reward);
+    env.events().publish(topics, data);
+}
+
+/// Emit when an admin removes a reward asset from the allowlist
+pub fn asset_removed(env: &Env, asset: Address) {
+    let topics = (TOPIC_ASSET_REMOVED, asset.clone());
+    let data = (asset,);
+    env.events().publish(topics, data);
+}
+
 /// Emit when a timelock is scheduled for unpause
 pub fn timelock_scheduled(env: &Env, scheduled_time: u64) {
     let topics = (TOPIC_TIMELOCK_SCHEDULED, scheduled_time);
@@ -96,18 +355,98 @@ pub fn submission_approved(
     env.events().publish(topics, data);
 }
 
+/// Emit when a quest's allowlist root is set, rotated or removed
+pub fn allowlist_root_set(
+    env: &Env,
+    quest_id: Symbol,
+    root: Option<BytesN<32>>,
+) {
+    // Topics: [EventName, QuestID]
+    let topics = (TOPIC_ALLOWLIST_ROOT_SET, quest_id);
+    // Data: (Root)
+    let data = (root,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when one milestone of a staged quest is approved
+pub fn milestone_approved(
+    env: &Env,
+    quest_id: Symbol,
+    submitter: Address,
+    milestone: u32,
+    verifier: Address,
+) {
+    // Topics: [EventName, QuestID, Submitter]
+    let topics = (TOPIC_MILESTONE_APPROVED, quest_id, submitter);
+    // Data: (Milestone Index, Verifier)
+    let data = (milestone, verifier);
+    env.events().publish(topics, data);
+}
+
+/// Emit when the ranking of a contest is finalized
+pub fn ranking_finalized(
+    env: &Env,
+    quest_id: Symbol,
+    verifier: Address,
+    ranked: Vec<Address>,
+    refund: i128,
+) {
+    // Topics: [EventName, QuestID]
+    let topics = (TOPIC_RANKING_FINALIZED, quest_id);
+    // Data: (Verifier, Winners By Rank, Refunded Amount)
+    let data = (verifier, ranked, refund);
+    env.events().publish(topics, data);
+}
+
+/// Emit when a submission is rejected
+pub fn submission_rejected(
+    env: &Env,
+    quest_id: Symbol,
+    submitter: Address,
+    verifier: Address,
+) {
+    // Topics: [EventName, QuestID, Submitter]
+    let topics = (TOPIC_SUBMISSION_REJECTED, quest_id, submitter);
+    // Data: (Verifier)
+    let data = (verifier,);
+    env.events().publish(topics, data);
+}
+
+/// Emit when the winner of a bounty is selected
+pub fn winner_selected(env: &Env, quest_id: Symbol, winner: Address, rejected: u32) {
+    // Topics: [EventName, QuestID, Winner]
+    let topics = (TOPIC_WINNER_SELECTED, quest_id, winner);
+    // Data: (Rejected Submissions)
+    let data = (rejected,);
+    env.events().publish(topics, data);
+}
+
 /// Emit when a user claims their reward
 pub fn reward_claimed(
     env: &Env,
     quest_id: Symbol,
     submitter: Address,
-    reward_asset: Address,
-    reward_amount: i128,
+    legs: Vec<(Address, i128, i128, i128)>,
 ) {
     // Topics: [EventName, QuestID, Submitter]
     let topics = (TOPIC_REWARD_CLAIMED, quest_id, submitter);
-    // Data: (Asset, Amount)
-    let data = (reward_asset, reward_amount);
+    // Data: [(Asset, Gross Amount, Fee, Net Amount)] for every reward leg
+    env.events().publish(topics, legs);
+}
+
+/// Emit when a hunter withdraws vested reward tokens
+pub fn vested_withdrawn(
+    env: &Env,
+    quest_id: Symbol,
+    beneficiary: Address,
+    reward_asset: Address,
+    amount: i128,
+    total_withdrawn: i128,
+) {
+    // Topics: [EventName, QuestID, Beneficiary]
+    let topics = (TOPIC_VESTED_WITHDRAWN, quest_id, beneficiary);
+    // Data: (Asset, Amount, Total Withdrawn)
+    let data = (reward_asset, amount, total_withdrawn);
     env.events().publish(topics, data);
 }
 
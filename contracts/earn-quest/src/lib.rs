@@ -1,20 +1,30 @@
 #![no_std]
 
 mod admin;
+mod allowlist;
 pub mod errors;
 mod events;
 mod security;
 mod payout;
+mod pool;
 mod reputation;
 pub mod storage;
 pub mod types;
 pub mod validation;
 mod quest;
 mod submission;
+mod milestone;
+mod chain;
+mod eligibility;
+mod team;
+mod vesting;
 
 use crate::errors::Error;
-use crate::types::{Badge, UserStats};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Symbol};
+use crate::types::{
+    AssetConfig, Badge, HoldingRule, Milestone, PayoutLimit, PoolAllowance, QuestNode, Reconciliation, RepeatPolicy, RewardLeg, RewardPool, RewardStrategy, RewardTier, TeamMember,
+    Sponsorship, Submission, UserStats, VestingPosition, VestingSchedule,
+};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Symbol, Vec};
 
 #[contract]
 pub struct EarnQuestContract;
@@ -45,7 +55,9 @@ impl EarnQuestContract {
         admin::is_admin(&env, &address)
     }
 
-    /// Register a new quest with full input validation
+    /// Register a new quest with full input validation, optionally drawing its
+    /// rewards from a reward pool instead of being funded (pool owner consents)
+    #[allow(clippy::too_many_arguments)]
     pub fn register_quest(
         env: Env,
         id: Symbol,
@@ -54,9 +66,17 @@ impl EarnQuestContract {
         reward_amount: i128,
         verifier: Address,
         deadline: u64,
+        max_claims: u32,
+        pool: Option<Symbol>,
     ) -> Result<(), Error> {
         security::require_not_paused(&env)?;
         creator.require_auth();
+        if let Some(pool_id) = &pool {
+            let owner = storage::get_pool(&env, pool_id)?.owner;
+            if owner != creator {
+                owner.require_auth();
+            }
+        }
 
         quest::register_quest(
             &env,
@@ -66,20 +86,185 @@ impl EarnQuestContract {
             reward_amount,
             &verifier,
             deadline,
+            max_claims,
+            pool,
         )
     }
 
-    /// Submit proof with input validation
+    /// Escrow the full reward budget (reward_amount * max_claims) from the creator
+    pub fn fund_quest(env: Env, quest_id: Symbol, creator: Address) -> Result<i128, Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        quest::fund_quest(&env, &quest_id, &creator)
+    }
+
+    /// Deposit additional reward budget into a live quest's escrow (creator only)
+    pub fn top_up_quest(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        quest::top_up_quest(&env, &quest_id, &creator, amount)
+    }
+
+    /// Raise the per-claim reward, escrowing the difference for unpaid claims (creator only)
+    pub fn increase_reward(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        new_amount: i128,
+    ) -> Result<i128, Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        quest::increase_reward(&env, &quest_id, &creator, new_amount)
+    }
+
+    /// Amend deadline, verifier and/or participant cap of a live quest (creator only)
+    pub fn amend_quest(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        new_deadline: Option<u64>,
+        new_verifier: Option<Address>,
+        new_max_claims: Option<u32>,
+    ) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        quest::amend_quest(
+            &env,
+            &quest_id,
+            &creator,
+            new_deadline,
+            new_verifier,
+            new_max_claims,
+        )
+    }
+
+    /// Cancel a quest and refund unspent escrow to the creator (creator only)
+    pub fn cancel_quest(env: Env, quest_id: Symbol, creator: Address) -> Result<i128, Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        quest::cancel_quest(&env, &quest_id, &creator)
+    }
+
+    /// Expire a quest after its claim window and refund leftover escrow to the creator
+    pub fn reclaim_expired(env: Env, quest_id: Symbol) -> Result<i128, Error> {
+        security::require_not_paused(&env)?;
+
+        quest::reclaim_expired(&env, &quest_id)
+    }
+
+    /// Admin: set seconds after a quest deadline during which rewards can be claimed
+    pub fn set_claim_window(env: Env, caller: Address, seconds: u64) -> Result<(), Error> {
+        quest::set_claim_window(&env, &caller, seconds)
+    }
+
+    /// Set the platform fee in basis points and its treasury (admin only)
+    pub fn set_platform_fee(
+        env: Env,
+        caller: Address,
+        fee_bps: u32,
+        treasury: Address,
+    ) -> Result<(), Error> {
+        admin::set_platform_fee(&env, &caller, fee_bps, &treasury)
+    }
+
+    /// Get the platform fee in basis points
+    pub fn get_platform_fee(env: Env) -> u32 {
+        storage::get_fee_bps(&env)
+    }
+
+    /// Get the treasury receiving platform fees
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        storage::get_treasury(&env)
+    }
+
+    /// Allowlist a reward asset with per-claim reward bounds (admin only)
+    pub fn allow_asset(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        min_reward: i128,
+        max_reward: i128,
+    ) -> Result<(), Error> {
+        admin::allow_asset(&env, &caller, &asset, min_reward, max_reward)
+    }
+
+    /// Remove a reward asset from the allowlist (admin only)
+    pub fn remove_asset(env: Env, caller: Address, asset: Address) -> Result<(), Error> {
+        admin::remove_asset(&env, &caller, &asset)
+    }
+
+    /// List all allowlisted reward assets
+    pub fn get_allowed_assets(env: Env) -> Vec<Address> {
+        storage::get_allowed_assets(&env)
+    }
+
+    /// Get the reward bounds of an allowlisted asset
+    pub fn get_asset_config(env: Env, asset: Address) -> Result<AssetConfig, Error> {
+        storage::get_asset_config(&env, &asset)
+    }
+
+    /// Get the claim window applied after quest deadlines
+    pub fn get_claim_window(env: Env) -> u64 {
+        storage::get_claim_window_seconds(&env)
+    }
+
+    /// Get the remaining escrowed reward budget of a quest
+    pub fn get_quest_escrow(env: Env, quest_id: Symbol) -> Result<i128, Error> {
+        let quest = storage::get_quest(&env, &quest_id)?;
+        Ok(storage::get_quest_escrow(&env, &quest_id, &quest.reward_asset))
+    }
+
+    /// Submit proof with input validation; `milestone` is 0 unless the quest is staged
+    /// and `allowlist_proof` is empty unless the quest is invite-only
     pub fn submit_proof(
         env: Env,
         quest_id: Symbol,
         submitter: Address,
+        milestone: u32,
         proof_hash: BytesN<32>,
+        allowlist_proof: Vec<BytesN<32>>,
     ) -> Result<(), Error> {
         security::require_not_paused(&env)?;
         submitter.require_auth();
 
-        submission::submit_proof(&env, &quest_id, &submitter, &proof_hash)
+        submission::submit_proof(&env, &quest_id, &submitter, milestone, &proof_hash, &allowlist_proof)
+    }
+
+    /// Submit proof for a team; every member signs and the reward is split by their shares
+    pub fn submit_team_proof(
+        env: Env,
+        quest_id: Symbol,
+        lead: Address,
+        members: Vec<TeamMember>,
+        milestone: u32,
+        proof_hash: BytesN<32>,
+        allowlist_proof: Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        lead.require_auth();
+        for entry in members.iter() {
+            if entry.member != lead {
+                entry.member.require_auth();
+            }
+        }
+
+        submission::submit_proof(&env, &quest_id, &lead, milestone, &proof_hash, &allowlist_proof)?;
+        team::register_team(&env, &quest_id, &lead, &members)
+    }
+
+    /// Get the members sharing a lead's submission; empty for solo submissions
+    pub fn get_team(env: Env, quest_id: Symbol, lead: Address) -> Vec<TeamMember> {
+        storage::get_team(&env, &quest_id, &lead)
     }
 
     /// Approve submission with status transition validation
@@ -95,45 +280,529 @@ impl EarnQuestContract {
         submission::approve_submission(&env, &quest_id, &submitter, &verifier)
     }
 
+    /// Approve one milestone of a staged quest
+    pub fn approve_milestone(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        milestone: u32,
+        verifier: Address,
+    ) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        verifier.require_auth();
+
+        milestone::approve(&env, &quest_id, &submitter, milestone, &verifier)
+    }
+
+    /// Select the single winning submission of a bounty, rejecting all other pending ones
+    pub fn select_winner(
+        env: Env,
+        quest_id: Symbol,
+        winner: Address,
+        verifier: Address,
+    ) -> Result<u32, Error> {
+        security::require_not_paused(&env)?;
+        verifier.require_auth();
+
+        submission::select_winner(&env, &quest_id, &winner, &verifier)
+    }
+
+    /// Rank approved contest entries by prize and refund unassigned prizes
+    pub fn finalize_ranking(
+        env: Env,
+        quest_id: Symbol,
+        verifier: Address,
+        ranked: Vec<Address>,
+    ) -> Result<i128, Error> {
+        security::require_not_paused(&env)?;
+        verifier.require_auth();
+
+        submission::finalize_ranking(&env, &quest_id, &verifier, ranked)
+    }
+
+    /// Get the addresses that entered a bounty
+    pub fn get_submitters(env: Env, quest_id: Symbol) -> Vec<Address> {
+        storage::get_quest_submitters(&env, &quest_id)
+    }
+
     /// Claim approved reward with full validation
     pub fn claim_reward(env: Env, quest_id: Symbol, submitter: Address) -> Result<(), Error> {
         // 1. Auth
         security::require_not_paused(&env)?;
         submitter.require_auth();
 
-        // 2. Validate claim (status transitions, limits)
-        submission::validate_claim(&env, &quest_id, &submitter)?;
+        // 2. Validate claim (status transitions, limits) and resolve its amount:
+        // contest entries are paid their rank's prize, tiered ones their tier's,
+        // staged ones every approved milestone, completing with the last one
+        let mut quest = storage::get_quest(&env, &quest_id)?;
+        let staged = !storage::get_milestones(&env, &quest_id).is_empty();
+        let completes = if staged {
+            let (amount, completes) = milestone::claimable(&env, &quest, &submitter)?;
+            quest.reward_amount = amount;
+            completes
+        } else {
+            submission::validate_claim(&env, &quest_id, &submitter)?;
+            quest.reward_amount = quest::claim_amount(&env, &quest, &submitter)?;
+            true
+        };
+        // Bundled legs are paid once per hunter, with the completing claim
+        let extra_rewards = if completes {
+            quest.extra_rewards.clone()
+        } else {
+            Vec::new(&env)
+        };
 
-        // 3. Data Retrieval for payout
-        let quest = storage::get_quest(&env, &quest_id)?;
+        // Circuit breaker: a claim over an asset's window limit pauses that
+        // asset's payouts instead of paying; the submission stays approved
+        // for after the unpause approvals resume them
+        let mut volume = Vec::new(&env);
+        volume.push_back((quest.reward_asset.clone(), quest.reward_amount));
+        for leg in extra_rewards.iter() {
+            volume.push_back((leg.asset, leg.amount));
+        }
+        if !security::record_payouts(&env, &volume)? {
+            return Ok(());
+        }
 
-        // 4. Payout
-        payout::transfer_reward(&env, &quest.reward_asset, &submitter, quest.reward_amount)?;
+        // Pool-backed quests move the reward from the pool into escrow first
+        if let Some(pool_id) = &quest.pool {
+            pool::draw_for_claim(&env, pool_id, &quest)?;
+        }
+
+        // 4. Payout (debits this quest's escrow), or lock it for vesting;
+        // team rewards are split between members by their shares
+        let schedule = storage::get_quest_vesting(&env, &quest_id);
+        if schedule.is_none() && quest.strategy == RewardStrategy::Mint {
+            quest::record_mint(&env, &quest_id, quest.reward_amount)?;
+        }
+
+        // Shares that round down to 0 are skipped; the lead keeps the dust
+        let recipients = team::recipients(&env, &quest_id, &submitter);
+        let mut payouts = Vec::new(&env);
+        for (i, recipient) in recipients.iter().enumerate() {
+            let mut legs = Vec::new(&env);
+            let amount = team::share_of(quest.reward_amount, &recipients, &submitter, i as u32);
+            if amount > 0 {
+                let fee = if let Some(schedule) = schedule.clone() {
+                    let mut share = storage::get_quest(&env, &quest_id)?;
+                    share.reward_amount = amount;
+                    vesting::start_vesting(&env, &share, &recipient.member, schedule)?
+                } else {
+                    payout::transfer_reward(
+                        &env,
+                        &quest_id,
+                        &quest.reward_asset,
+                        &recipient.member,
+                        amount,
+                        &quest.strategy,
+                    )?
+                };
+                legs.push_back((quest.reward_asset.clone(), amount, fee, amount - fee));
+            }
+
+            // Bundled legs are paid together; any failure reverts the whole claim
+            for leg in extra_rewards.iter() {
+                let leg_amount = team::share_of(leg.amount, &recipients, &submitter, i as u32);
+                if leg_amount == 0 {
+                    continue;
+                }
+                let leg_fee = payout::transfer_reward(
+                    &env,
+                    &quest_id,
+                    &leg.asset,
+                    &recipient.member,
+                    leg_amount,
+                    &RewardStrategy::Escrow,
+                )?;
+                legs.push_back((leg.asset, leg_amount, leg_fee, leg_amount - leg_fee));
+            }
+            payouts.push_back((recipient.member, legs));
+        }
 
         // 5. State Update
-        storage::update_submission_status(
-            &env,
-            &quest_id,
-            &submitter,
-            types::SubmissionStatus::Paid,
-        )?;
-        storage::increment_quest_claims(&env, &quest_id)?;
+        if staged {
+            milestone::mark_paid(&env, &quest_id, &submitter)?;
+        }
+        if completes {
+            storage::update_submission_status(
+                &env,
+                &quest_id,
+                &submitter,
+                types::SubmissionStatus::Paid,
+            )?;
+            storage::increment_quest_claims(&env, &quest_id)?;
+        }
+        if quest::tracks_reserved(&env, &quest_id) {
+            storage::adjust_reserved_rewards(&env, &quest_id, -quest.reward_amount);
+        }
 
-        // EMIT EVENT: RewardClaimed
-        events::reward_claimed(
-            &env,
-            quest_id.clone(),
-            submitter.clone(),
-            quest.reward_asset,
-            quest.reward_amount,
-        );
+        for (i, (member, legs)) in payouts.iter().enumerate() {
+            // EMIT EVENT: RewardClaimed
+            if !legs.is_empty() {
+                events::reward_claimed(&env, quest_id.clone(), member.clone(), legs);
+            }
 
-        // 6. Award XP for quest completion
-        reputation::award_xp(&env, &submitter, 100)?;
+            // 6. Award XP for quest completion, split between team members by share
+            if completes {
+                storage::record_quest_completion(&env, &quest_id, &member);
+                let xp = team::share_of(100, &recipients, &submitter, i as u32) as u64;
+                reputation::award_xp(&env, &member, xp)?;
+            }
+        }
 
         Ok(())
     }
 
+    /// Bundle extra reward assets paid alongside the primary reward (creator only, before funding)
+    pub fn set_reward_bundle(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        legs: Vec<RewardLeg>,
+    ) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        quest::set_reward_bundle(&env, &quest_id, &creator, legs)
+    }
+
+    /// Contribute to a quest's reward escrow as a sponsor
+    pub fn sponsor_quest(
+        env: Env,
+        quest_id: Symbol,
+        sponsor: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        security::require_not_paused(&env)?;
+        sponsor.require_auth();
+
+        quest::sponsor_quest(&env, &quest_id, &sponsor, amount)
+    }
+
+    /// Get every contribution to a quest's reward escrow
+    pub fn get_sponsors(env: Env, quest_id: Symbol) -> Result<Vec<Sponsorship>, Error> {
+        storage::get_quest(&env, &quest_id)?;
+        Ok(storage::get_quest_sponsors(&env, &quest_id))
+    }
+
+    /// Allow an issuer to create mint-on-claim quests for an asset (admin only)
+    pub fn set_mintable_asset(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        issuer: Address,
+    ) -> Result<(), Error> {
+        admin::set_mintable_asset(&env, &caller, &asset, &issuer)
+    }
+
+    /// Switch a quest to minting its rewards on claim, up to a total cap (asset issuer only)
+    pub fn set_mint_mode(env: Env, quest_id: Symbol, creator: Address, mint_cap: i128) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        quest::set_mint_mode(&env, &quest_id, &creator, mint_cap)
+    }
+
+    /// Turn a single-claim quest into a bounty with one verifier-selected winner (creator only)
+    pub fn set_bounty_mode(env: Env, quest_id: Symbol, creator: Address) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        quest::set_bounty_mode(&env, &quest_id, &creator)
+    }
+
+    /// Turn a quest into a ranked contest with one prize per rank (creator only, before funding)
+    pub fn set_prize_table(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        prizes: Vec<i128>,
+    ) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        quest::set_prize_table(&env, &quest_id, &creator, prizes)
+    }
+
+    /// Pay early approvals more with reward tiers in approval order (creator only, before funding)
+    pub fn set_reward_tiers(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        tiers: Vec<RewardTier>,
+    ) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        quest::set_reward_tiers(&env, &quest_id, &creator, tiers)
+    }
+
+    /// Get the early-bird reward tiers of a quest
+    pub fn get_reward_tiers(env: Env, quest_id: Symbol) -> Vec<RewardTier> {
+        storage::get_reward_tiers(&env, &quest_id)
+    }
+
+    /// Split the per-claim reward into ordered milestones (creator only, before any submission)
+    pub fn set_milestones(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        milestones: Vec<Milestone>,
+    ) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        milestone::set_milestones(&env, &quest_id, &creator, milestones)
+    }
+
+    /// Make a quest invite-only or rotate its allowlist Merkle root (creator only)
+    pub fn set_allowlist_root(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        root: BytesN<32>,
+    ) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        allowlist::set_allowlist_root(&env, &quest_id, &creator, Some(root))
+    }
+
+    /// Open an invite-only quest to everyone again (creator only)
+    pub fn remove_allowlist_root(env: Env, quest_id: Symbol, creator: Address) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        allowlist::set_allowlist_root(&env, &quest_id, &creator, None)
+    }
+
+    /// Get the allowlist Merkle root of an invite-only quest
+    pub fn get_allowlist_root(env: Env, quest_id: Symbol) -> Option<BytesN<32>> {
+        storage::get_allowlist_root(&env, &quest_id)
+    }
+
+    /// Require hunters to hold minimum balances of sponsor tokens (creator only, before any submission)
+    pub fn set_holding_rules(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        rules: Vec<HoldingRule>,
+    ) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        eligibility::set_holding_rules(&env, &quest_id, &creator, rules)
+    }
+
+    /// Get the token-holding rules of a quest
+    pub fn get_holding_rules(env: Env, quest_id: Symbol) -> Vec<HoldingRule> {
+        storage::get_holding_rules(&env, &quest_id)
+    }
+
+    /// Require a minimum level and badges to submit proof (creator only, before any submission)
+    pub fn set_reputation_gate(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        min_level: u32,
+        required_badges: Vec<Badge>,
+    ) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        quest::set_reputation_gate(&env, &quest_id, &creator, min_level, required_badges)
+    }
+
+    /// Require hunters to be paid for other quests before submitting (creator only)
+    pub fn set_prerequisites(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        prerequisites: Vec<Symbol>,
+    ) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        chain::set_prerequisites(&env, &quest_id, &creator, prerequisites)
+    }
+
+    /// Get a quest and everything it depends on, each with its direct prerequisites
+    pub fn get_quest_chain(env: Env, quest_id: Symbol) -> Result<Vec<QuestNode>, Error> {
+        chain::quest_chain(&env, &quest_id)
+    }
+
+    /// Get the milestones of a staged quest
+    pub fn get_milestones(env: Env, quest_id: Symbol) -> Vec<Milestone> {
+        storage::get_milestones(&env, &quest_id)
+    }
+
+    /// Get the prizes of a contest by rank
+    pub fn get_prize_table(env: Env, quest_id: Symbol) -> Vec<i128> {
+        storage::get_prize_table(&env, &quest_id)
+    }
+
+    /// Create a shared reward pool in an allowlisted asset
+    pub fn create_pool(env: Env, pool_id: Symbol, owner: Address, asset: Address) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        owner.require_auth();
+
+        pool::create_pool(&env, &pool_id, &owner, &asset)
+    }
+
+    /// Deposit tokens into a reward pool
+    pub fn deposit_to_pool(env: Env, pool_id: Symbol, from: Address, amount: i128) -> Result<i128, Error> {
+        security::require_not_paused(&env)?;
+        from.require_auth();
+
+        pool::deposit(&env, &pool_id, &from, amount)
+    }
+
+    /// Withdraw undrawn tokens from a reward pool (pool owner only)
+    pub fn withdraw_from_pool(
+        env: Env,
+        pool_id: Symbol,
+        owner: Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        security::require_not_paused(&env)?;
+        owner.require_auth();
+
+        pool::withdraw(&env, &pool_id, &owner, amount)
+    }
+
+    /// Change how much a pool-backed quest may draw from its pool (pool owner only)
+    pub fn set_pool_cap(
+        env: Env,
+        pool_id: Symbol,
+        owner: Address,
+        quest_id: Symbol,
+        cap: i128,
+    ) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        owner.require_auth();
+
+        pool::set_quest_cap(&env, &pool_id, &owner, &quest_id, cap)
+    }
+
+    /// Get a reward pool
+    pub fn get_pool(env: Env, pool_id: Symbol) -> Result<RewardPool, Error> {
+        storage::get_pool(&env, &pool_id)
+    }
+
+    /// Get how much a quest may draw from a pool and has drawn so far
+    pub fn get_pool_allowance(
+        env: Env,
+        pool_id: Symbol,
+        quest_id: Symbol,
+    ) -> Result<PoolAllowance, Error> {
+        storage::get_pool_allowance(&env, &pool_id, &quest_id)
+    }
+
+    /// Get the remaining escrow of a quest in one of its reward assets
+    pub fn get_asset_escrow(env: Env, quest_id: Symbol, asset: Address) -> Result<i128, Error> {
+        storage::get_quest(&env, &quest_id)?;
+        Ok(storage::get_quest_escrow(&env, &quest_id, &asset))
+    }
+
+    /// Configure the vesting schedule before any approval (creator only)
+    pub fn set_vesting(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        schedule: VestingSchedule,
+    ) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        quest::set_vesting(&env, &quest_id, &creator, Some(schedule))
+    }
+
+    /// Remove the vesting schedule before any approval (creator only)
+    pub fn remove_vesting(env: Env, quest_id: Symbol, creator: Address) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        quest::set_vesting(&env, &quest_id, &creator, None)
+    }
+
+    /// Let each user complete a quest repeatedly, with a cooldown between attempts (creator only)
+    pub fn set_repeat_policy(
+        env: Env,
+        quest_id: Symbol,
+        creator: Address,
+        policy: RepeatPolicy,
+    ) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        quest::set_repeat_policy(&env, &quest_id, &creator, Some(policy))
+    }
+
+    /// Make a quest single-completion again before any approval (creator only)
+    pub fn remove_repeat_policy(env: Env, quest_id: Symbol, creator: Address) -> Result<(), Error> {
+        security::require_not_paused(&env)?;
+        creator.require_auth();
+
+        quest::set_repeat_policy(&env, &quest_id, &creator, None)
+    }
+
+    /// Get the repeat policy of a quest, if it is repeatable
+    pub fn get_repeat_policy(env: Env, quest_id: Symbol) -> Option<RepeatPolicy> {
+        storage::get_repeat_policy(&env, &quest_id)
+    }
+
+    /// Get one attempt of a user's submissions; attempts are numbered from 1
+    pub fn get_submission_attempt(
+        env: Env,
+        quest_id: Symbol,
+        submitter: Address,
+        attempt: u32,
+    ) -> Result<Submission, Error> {
+        let current = storage::get_submission(&env, &quest_id, &submitter)?;
+        if current.attempt == attempt {
+            return Ok(current);
+        }
+        storage::get_submission_attempt(&env, &quest_id, &submitter, attempt)
+    }
+
+    /// Withdraw the vested part of a claimed reward
+    pub fn withdraw_vested(env: Env, quest_id: Symbol, beneficiary: Address) -> Result<i128, Error> {
+        security::require_not_paused(&env)?;
+        beneficiary.require_auth();
+
+        vesting::withdraw_vested(&env, &quest_id, &beneficiary)
+    }
+
+    /// Get the vesting position of a beneficiary
+    pub fn get_vesting(
+        env: Env,
+        quest_id: Symbol,
+        beneficiary: Address,
+    ) -> Result<VestingPosition, Error> {
+        storage::get_vesting_position(&env, &quest_id, &beneficiary)
+    }
+
+    /// Get the amount of a vesting reward unlocked so far (withdrawn or not)
+    pub fn get_vested_amount(env: Env, quest_id: Symbol, beneficiary: Address) -> Result<i128, Error> {
+        let position = storage::get_vesting_position(&env, &quest_id, &beneficiary)?;
+        Ok(vesting::vested_amount(&env, &position))
+    }
+
+    /// Get the amount of a vesting reward still locked
+    pub fn get_unvested_amount(
+        env: Env,
+        quest_id: Symbol,
+        beneficiary: Address,
+    ) -> Result<i128, Error> {
+        let position = storage::get_vesting_position(&env, &quest_id, &beneficiary)?;
+        Ok(position.total_amount - vesting::vested_amount(&env, &position))
+    }
+
+    /// Get contract balance, escrowed liabilities and surplus for a reward asset
+    pub fn reconcile(env: Env, asset: Address) -> Reconciliation {
+        payout::reconcile(&env, &asset)
+    }
+
     /// Get user reputation stats
     pub fn get_user_stats(env: Env, user: Address) -> UserStats {
         reputation::get_user_stats(&env, &user)
@@ -165,6 +834,37 @@ impl EarnQuestContract {
         security::emergency_unpause(&env, &caller)
     }
 
+    /// Cap an asset's payout volume per rolling window in seconds; zero removes the cap (admin only)
+    pub fn set_payout_limit(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        max_amount: i128,
+        window_seconds: u64,
+    ) -> Result<(), Error> {
+        security::set_payout_limit(&env, &caller, &asset, max_amount, window_seconds)
+    }
+
+    /// Get an asset's payout limit, if any
+    pub fn get_payout_limit(env: Env, asset: Address) -> Option<PayoutLimit> {
+        storage::get_payout_limit(&env, &asset)
+    }
+
+    /// Get the amount of an asset paid out within its current window
+    pub fn get_window_volume(env: Env, asset: Address) -> i128 {
+        security::window_volume(&env, &asset)
+    }
+
+    /// Whether the circuit breaker has paused an asset's payouts
+    pub fn is_payouts_paused(env: Env, asset: Address) -> bool {
+        security::is_payouts_paused(&env, &asset)
+    }
+
+    /// Resume an asset's payouts after the circuit breaker tripped (unpause approvals and timelock)
+    pub fn resume_payouts(env: Env, caller: Address, asset: Address) -> Result<(), Error> {
+        security::resume_payouts(&env, &caller, &asset)
+    }
+
     /// Emergency withdrawal when paused (admin only) with amount validation
     pub fn emergency_withdraw(
         env: Env,
@@ -178,6 +878,19 @@ impl EarnQuestContract {
         security::emergency_withdraw(&env, &caller, &asset, &to, amount)
     }
 
+    /// Emergency: approve withdrawing an amount to an address below escrowed liabilities,
+    /// writing off the escrow of the listed quests (admin multisig approval)
+    pub fn emergency_approve_override(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        to: Address,
+        amount: i128,
+        quests: Vec<Symbol>,
+    ) -> Result<(), Error> {
+        security::emergency_approve_override(&env, &caller, &asset, &to, amount, &quests)
+    }
+
     /// Admin: set unpause approvals threshold
     pub fn set_unpause_threshold(env: Env, caller: Address, threshold: u32) -> Result<(), Error> {
         security::set_unpause_threshold(&env, &caller, threshold)
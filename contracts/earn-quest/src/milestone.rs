@@ -0,0 +1,230 @@
+use crate::errors::Error;
+use crate::events;
+use crate::quest;
+use crate::storage;
+use crate::types::{Milestone, Quest, QuestKind, QuestStatus, Submission, SubmissionStatus};
+use crate::validation;
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
+
+/// Split a quest's per-claim reward into ordered milestones (creator only).
+///
+/// Each milestone pays its share of `reward_amount`; hunters deliver them
+/// in order and claim every approved milestone once.
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is Active or Paused, neither a bounty nor a contest, without
+///   reward tiers, a vesting schedule or a repeat policy
+/// - Nobody has submitted proof yet
+/// - Milestones are valid (see `validation::validate_milestones`)
+pub fn set_milestones(
+    env: &Env,
+    id: &Symbol,
+    creator: &Address,
+    milestones: Vec<Milestone>,
+) -> Result<(), Error> {
+    let quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    quest::validate_quest_is_live(&quest.status)?;
+
+    if quest.kind != QuestKind::Standard
+        || !storage::get_reward_tiers(env, id).is_empty()
+        || storage::get_quest_vesting(env, id).is_some()
+        || storage::get_repeat_policy(env, id).is_some()
+        || storage::has_submitters(env, id)
+    {
+        return Err(Error::QuestTermsLocked);
+    }
+
+    validation::validate_milestones(&milestones)?;
+    storage::set_milestones(env, id, &milestones);
+
+    Ok(())
+}
+
+/// Amount paid for milestone `index`; the last one takes the rounding dust
+/// so all milestones add up to the full reward.
+pub fn milestone_amount(reward_amount: i128, milestones: &Vec<Milestone>, index: u32) -> i128 {
+    let share = |m: Milestone| reward_amount * m.share_bps as i128 / 10_000;
+    if index + 1 == milestones.len() {
+        let earlier: i128 = milestones.iter().take(index as usize).map(share).sum();
+        return reward_amount - earlier;
+    }
+    milestones.get(index).map(share).unwrap_or(0)
+}
+
+/// Record the proof of one milestone.
+///
+/// Validates:
+/// - Milestone exists
+/// - The previous milestone has been approved
+/// - This milestone has not been approved yet; pending proofs can be replaced
+pub fn submit_proof(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    milestone: u32,
+    proof_hash: &BytesN<32>,
+) -> Result<(), Error> {
+    let milestones = storage::get_milestones(env, quest_id);
+    if milestone >= milestones.len() {
+        return Err(Error::MilestoneNotFound);
+    }
+
+    if milestone > 0 {
+        let previous = storage::get_milestone_submission(env, quest_id, submitter, milestone - 1)
+            .map_err(|_| Error::MilestoneOutOfOrder)?;
+        if previous.status != SubmissionStatus::Approved && previous.status != SubmissionStatus::Paid {
+            return Err(Error::MilestoneOutOfOrder);
+        }
+    }
+
+    if let Ok(existing) = storage::get_milestone_submission(env, quest_id, submitter, milestone) {
+        if existing.status != SubmissionStatus::Pending {
+            return Err(Error::InvalidStatusTransition);
+        }
+    }
+
+    let submission = Submission {
+        quest_id: quest_id.clone(),
+        submitter: submitter.clone(),
+        proof_hash: proof_hash.clone(),
+        status: SubmissionStatus::Pending,
+        timestamp: env.ledger().timestamp(),
+        tier: 0,
+        attempt: 1,
+    };
+    storage::set_milestone_submission(env, milestone, &submission);
+
+    Ok(())
+}
+
+/// Approve one milestone of a submitter (verifier only).
+///
+/// The first approved milestone turns the submitter's quest submission
+/// Approved, counting it as a pending claim until the last milestone is paid.
+///
+/// Validates:
+/// - Quest exists and caller is the verifier
+/// - Quest has not been cancelled
+/// - Milestone submission exists
+/// - Milestone status transition (Pending -> Approved) is valid
+/// - A submitter's first approval stays within the quest's claim limit,
+///   counting paid and pending claims, so partial payouts never go to more
+///   hunters than the quest pays for
+pub fn approve(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    milestone: u32,
+    verifier: &Address,
+) -> Result<(), Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+
+    if *verifier != quest.verifier {
+        return Err(Error::Unauthorized);
+    }
+
+    // No new obligations once the escrow has been refunded
+    if quest.status == QuestStatus::Cancelled {
+        return Err(Error::QuestNotActive);
+    }
+
+    let milestones = storage::get_milestones(env, quest_id);
+    if milestone >= milestones.len() {
+        return Err(Error::MilestoneNotFound);
+    }
+
+    let mut entry = storage::get_milestone_submission(env, quest_id, submitter, milestone)?;
+    validation::validate_submission_status_transition(&entry.status, &SubmissionStatus::Approved)?;
+
+    let submission = storage::get_submission(env, quest_id, submitter)?;
+    let first_approval = submission.status == SubmissionStatus::Pending;
+    if first_approval && quest.total_claims + quest.pending_claims >= quest.max_claims {
+        return Err(Error::QuestFull);
+    }
+
+    entry.status = SubmissionStatus::Approved;
+    storage::set_milestone_submission(env, milestone, &entry);
+    storage::adjust_reserved_rewards(
+        env,
+        quest_id,
+        milestone_amount(quest.reward_amount, &milestones, milestone),
+    );
+
+    if first_approval {
+        storage::update_submission_status(env, quest_id, submitter, SubmissionStatus::Approved)?;
+        storage::increment_quest_pending_claims(env, quest_id)?;
+    }
+
+    // EMIT EVENT: MilestoneApproved
+    events::milestone_approved(env, quest_id.clone(), submitter.clone(), milestone, verifier.clone());
+
+    Ok(())
+}
+
+/// Work out what a claim on a staged quest pays, without changing state.
+///
+/// Returns the total of every approved-but-unpaid milestone, and whether
+/// the claim completes the submitter's quest (every milestone paid after it).
+///
+/// Validates:
+/// - Quest has not been swept as Expired
+/// - Submission exists and is not fully paid (AlreadyClaimed)
+/// - At least one milestone is approved and unpaid
+/// - A completing claim stays within the quest's claim limit
+pub fn claimable(env: &Env, quest: &Quest, submitter: &Address) -> Result<(i128, bool), Error> {
+    if quest.status == QuestStatus::Expired {
+        return Err(Error::QuestExpired);
+    }
+
+    let submission = storage::get_submission(env, &quest.id, submitter)?;
+    if submission.status == SubmissionStatus::Paid {
+        return Err(Error::AlreadyClaimed);
+    }
+
+    let milestones = storage::get_milestones(env, &quest.id);
+    let mut amount = 0;
+    let mut completes = true;
+    for index in 0..milestones.len() {
+        match storage::get_milestone_submission(env, &quest.id, submitter, index) {
+            Ok(entry) if entry.status == SubmissionStatus::Approved => {
+                amount += milestone_amount(quest.reward_amount, &milestones, index);
+            }
+            Ok(entry) if entry.status == SubmissionStatus::Paid => {}
+            _ => completes = false,
+        }
+    }
+
+    if amount == 0 {
+        return Err(Error::InvalidStatusTransition);
+    }
+
+    if completes {
+        validation::validate_quest_claims_limit(quest.total_claims)?;
+        if quest.total_claims >= quest.max_claims {
+            return Err(Error::QuestFull);
+        }
+    }
+
+    Ok((amount, completes))
+}
+
+/// Mark every approved milestone of a submitter as paid.
+pub fn mark_paid(env: &Env, quest_id: &Symbol, submitter: &Address) -> Result<(), Error> {
+    let milestones = storage::get_milestones(env, quest_id);
+    for index in 0..milestones.len() {
+        if let Ok(mut entry) = storage::get_milestone_submission(env, quest_id, submitter, index) {
+            if entry.status == SubmissionStatus::Approved {
+                validation::validate_submission_status_transition(&entry.status, &SubmissionStatus::Paid)?;
+                entry.status = SubmissionStatus::Paid;
+                storage::set_milestone_submission(env, index, &entry);
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,40 +1,235 @@
-use soroban_sdk::{token, Address, Env};
+use soroban_sdk::{token, Address, Env, Symbol};
+use crate::types::{Reconciliation, RewardStrategy};
 use crate::errors::Error;
+use crate::storage;
 
-/// Transfer rewards from the contract escrow to the user.
-/// 
-/// This function handles the low-level token transfer and ensures
-/// the contract has sufficient balance.
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Pull tokens from a depositor into the escrow of a quest.
+///
+/// The depositor must authorize the token transfer. The escrow balance
+/// of the quest is credited only after the transfer succeeds.
+pub fn deposit_escrow(
+    env: &Env,
+    quest_id: &Symbol,
+    reward_asset: &Address,
+    from: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidRewardAmount);
+    }
+
+    let token_client = token::Client::new(env, reward_asset);
+    let contract_address = env.current_contract_address();
+
+    let transfer_result = token_client.try_transfer(from, &contract_address, &amount);
+
+    match transfer_result {
+        Ok(Ok(_)) => {
+            let escrow = storage::get_quest_escrow(env, quest_id, reward_asset);
+            storage::set_quest_escrow(env, quest_id, reward_asset, escrow + amount);
+            storage::adjust_liabilities(env, reward_asset, amount);
+            Ok(())
+        }
+        _ => Err(Error::TransferFailed),
+    }
+}
+
+/// Transfer rewards to the user according to the quest's reward strategy.
+///
+/// With `RewardStrategy::Escrow` the reward leaves the escrow of the quest;
+/// both the escrow and the contract must hold sufficient balance, so one
+/// quest can never pay out of another quest's deposit. With
+/// `RewardStrategy::Mint` the contract mints the reward as token admin.
+/// The platform fee is routed to the treasury and the user receives
+/// the net amount. Returns the fee taken.
 pub fn transfer_reward(
     env: &Env,
+    quest_id: &Symbol,
+    reward_asset: &Address,
+    to: &Address,
+    amount: i128,
+    strategy: &RewardStrategy,
+) -> Result<i128, Error> {
+    // 0. Asset validation happens at registration against the asset allowlist
+    match strategy {
+        RewardStrategy::Escrow => {
+            let fee = pay_platform_fee(env, quest_id, reward_asset, amount)?;
+            release_escrow(env, quest_id, reward_asset, to, amount - fee)?;
+            Ok(fee)
+        }
+        RewardStrategy::Mint => {
+            let fee = match storage::get_treasury(env) {
+                Some(treasury) => {
+                    let fee = platform_fee(env, amount);
+                    if fee > 0 {
+                        mint_tokens(env, reward_asset, &treasury, fee)?;
+                    }
+                    fee
+                }
+                None => 0,
+            };
+            mint_tokens(env, reward_asset, to, amount - fee)?;
+            Ok(fee)
+        }
+    }
+}
+
+/// Mint tokens to a recipient; the contract must be the token's admin.
+fn mint_tokens(env: &Env, asset: &Address, to: &Address, amount: i128) -> Result<(), Error> {
+    let token_admin = token::StellarAssetClient::new(env, asset);
+
+    match token_admin.try_mint(to, &amount) {
+        Ok(Ok(_)) => Ok(()),
+        _ => Err(Error::TransferFailed),
+    }
+}
+
+/// Compute the platform fee owed on a gross payout.
+pub fn platform_fee(env: &Env, amount: i128) -> i128 {
+    amount * storage::get_fee_bps(env) as i128 / BPS_DENOMINATOR
+}
+
+/// Send the platform fee on a gross payout from the quest escrow to the treasury.
+///
+/// Returns the fee taken, which is 0 when no fee is configured.
+pub fn pay_platform_fee(
+    env: &Env,
+    quest_id: &Symbol,
+    reward_asset: &Address,
+    amount: i128,
+) -> Result<i128, Error> {
+    let fee = platform_fee(env, amount);
+    if fee > 0 {
+        if let Some(treasury) = storage::get_treasury(env) {
+            release_escrow(env, quest_id, reward_asset, &treasury, fee)?;
+            return Ok(fee);
+        }
+    }
+    Ok(0)
+}
+
+/// Release already-charged vested tokens from the escrow of a quest.
+pub fn release_vested(
+    env: &Env,
+    quest_id: &Symbol,
     reward_asset: &Address,
     to: &Address,
     amount: i128,
 ) -> Result<(), Error> {
-    // 0. Asset validation (basic check that it's a valid address is handled by SDK type)
-    // Additional validation could be checking against a whitelist if required.
+    release_escrow(env, quest_id, reward_asset, to, amount)
+}
 
+/// Refund unspent escrow of a quest back to its creator.
+pub fn refund_escrow(
+    env: &Env,
+    quest_id: &Symbol,
+    reward_asset: &Address,
+    creator: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    release_escrow(env, quest_id, reward_asset, creator, amount)
+}
+
+/// Move tokens out of the escrow of a quest, debiting it on success.
+fn release_escrow(
+    env: &Env,
+    quest_id: &Symbol,
+    reward_asset: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), Error> {
     if amount <= 0 {
         return Err(Error::InvalidRewardAmount);
     }
 
+    // 1. Escrow Checking
+    let escrow = storage::get_quest_escrow(env, quest_id, reward_asset);
+    if escrow < amount {
+        return Err(Error::InsufficientBalance);
+    }
+
     let token_client = token::Client::new(env, reward_asset);
     let contract_address = env.current_contract_address();
 
-    // 1. Balance Checking
+    // 2. Balance Checking
     let balance = token_client.balance(&contract_address);
     if balance < amount {
         return Err(Error::InsufficientBalance);
     }
 
-    // 2. Transfer logic
+    // 3. Transfer logic
     // The contract authorizes this transfer as it owns the funds.
     let transfer_result = token_client.try_transfer(&contract_address, to, &amount);
 
-    // 3. Error Handling
+    // 4. Error Handling
     match transfer_result {
-        Ok(Ok(_)) => Ok(()),
+        Ok(Ok(_)) => {
+            storage::set_quest_escrow(env, quest_id, reward_asset, escrow - amount);
+            storage::adjust_liabilities(env, reward_asset, -amount);
+            Ok(())
+        }
         Ok(Err(_)) => Err(Error::TransferFailed), // Token logic error
         Err(_) => Err(Error::TransferFailed),     // Cross-contract call error
     }
 }
+
+/// Pull tokens from a depositor into a shared reward pool.
+///
+/// The pool balance itself is tracked by the caller (see `pool` module);
+/// this only moves tokens and records the new liability.
+pub fn deposit_pool(env: &Env, asset: &Address, from: &Address, amount: i128) -> Result<(), Error> {
+    let token_client = token::Client::new(env, asset);
+    let contract_address = env.current_contract_address();
+
+    match token_client.try_transfer(from, &contract_address, &amount) {
+        Ok(Ok(_)) => {
+            storage::adjust_liabilities(env, asset, amount);
+            Ok(())
+        }
+        _ => Err(Error::TransferFailed),
+    }
+}
+
+/// Send tokens out of a shared reward pool.
+pub fn withdraw_pool(env: &Env, asset: &Address, to: &Address, amount: i128) -> Result<(), Error> {
+    let token_client = token::Client::new(env, asset);
+    let contract_address = env.current_contract_address();
+
+    if token_client.balance(&contract_address) < amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    match token_client.try_transfer(&contract_address, to, &amount) {
+        Ok(Ok(_)) => {
+            storage::adjust_liabilities(env, asset, -amount);
+            Ok(())
+        }
+        _ => Err(Error::TransferFailed),
+    }
+}
+
+/// Move pool funds into the escrow of a quest so a claim can be paid from it.
+///
+/// No tokens move and liabilities are unchanged: the amount is only
+/// reassigned from the pool to the quest.
+pub fn credit_escrow_from_pool(env: &Env, quest_id: &Symbol, asset: &Address, amount: i128) {
+    let escrow = storage::get_quest_escrow(env, quest_id, asset);
+    storage::set_quest_escrow(env, quest_id, asset, escrow + amount);
+}
+
+/// Compare the contract's balance of an asset with what it owes to quests.
+///
+/// A negative surplus means the contract cannot cover all escrowed rewards.
+pub fn reconcile(env: &Env, asset: &Address) -> Reconciliation {
+    let token_client = token::Client::new(env, asset);
+    let balance = token_client.balance(&env.current_contract_address());
+    let liabilities = storage::get_liabilities(env, asset);
+
+    Reconciliation {
+        balance,
+        liabilities,
+        surplus: balance - liabilities,
+    }
+}
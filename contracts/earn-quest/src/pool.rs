@@ -0,0 +1,161 @@
+use crate::errors::Error;
+use crate::events;
+use crate::payout;
+use crate::quest;
+use crate::storage;
+use crate::types::{PoolAllowance, Quest, RewardPool};
+use crate::validation;
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Create an empty reward pool in an allowlisted asset.
+///
+/// Validates:
+/// - Pool ID length and uniqueness
+/// - Asset is allowlisted
+pub fn create_pool(env: &Env, id: &Symbol, owner: &Address, asset: &Address) -> Result<(), Error> {
+    validation::validate_symbol_length(id)?;
+
+    if storage::has_pool(env, id) {
+        return Err(Error::PoolAlreadyExists);
+    }
+
+    storage::get_asset_config(env, asset)?;
+
+    let pool = RewardPool {
+        id: id.clone(),
+        owner: owner.clone(),
+        asset: asset.clone(),
+        balance: 0,
+    };
+    storage::set_pool(env, &pool);
+
+    // EMIT EVENT: PoolCreated
+    events::pool_created(env, id.clone(), owner.clone(), asset.clone());
+
+    Ok(())
+}
+
+/// Deposit tokens into a pool. Anyone may fund a pool.
+pub fn deposit(env: &Env, id: &Symbol, from: &Address, amount: i128) -> Result<i128, Error> {
+    let mut pool = storage::get_pool(env, id)?;
+    validation::validate_reward_amount(amount)?;
+
+    payout::deposit_pool(env, &pool.asset, from, amount)?;
+    pool.balance += amount;
+    storage::set_pool(env, &pool);
+
+    // EMIT EVENT: PoolDeposited
+    events::pool_deposited(env, id.clone(), from.clone(), amount, pool.balance);
+
+    Ok(pool.balance)
+}
+
+/// Withdraw undrawn tokens from a pool back to its owner.
+pub fn withdraw(env: &Env, id: &Symbol, owner: &Address, amount: i128) -> Result<i128, Error> {
+    let mut pool = storage::get_pool(env, id)?;
+
+    if *owner != pool.owner {
+        return Err(Error::Unauthorized);
+    }
+
+    validation::validate_reward_amount(amount)?;
+    if amount > pool.balance {
+        return Err(Error::PoolExhausted);
+    }
+
+    payout::withdraw_pool(env, &pool.asset, owner, amount)?;
+    pool.balance -= amount;
+    storage::set_pool(env, &pool);
+
+    // EMIT EVENT: PoolWithdrawn
+    events::pool_withdrawn(env, id.clone(), owner.clone(), amount, pool.balance);
+
+    Ok(pool.balance)
+}
+
+/// Let a quest being registered draw its rewards from a pool instead of
+/// being funded, up to its full budget (`reward_amount * max_claims`).
+///
+/// Validates:
+/// - Pool exists and the owner consents (auth checked by the entrypoint)
+/// - Quest pays in the pool's asset
+pub fn attach_quest(env: &Env, pool_id: &Symbol, quest: &mut Quest) -> Result<(), Error> {
+    let pool = storage::get_pool(env, pool_id)?;
+
+    if quest.reward_asset != pool.asset {
+        return Err(Error::InvalidAsset);
+    }
+
+    let cap = quest
+        .reward_amount
+        .checked_mul(quest.max_claims as i128)
+        .ok_or(Error::AmountTooLarge)?;
+    storage::set_pool_allowance(env, pool_id, &quest.id, &PoolAllowance { cap, spent: 0 });
+    quest.pool = Some(pool_id.clone());
+
+    // EMIT EVENT: PoolQuestAttached
+    events::pool_quest_attached(env, pool_id.clone(), quest.id.clone(), cap);
+
+    Ok(())
+}
+
+/// Change how much a quest may draw from its pool in total.
+///
+/// Validates:
+/// - Caller owns the pool
+/// - Quest exists, is Active or Paused and draws from this pool
+/// - Cap is within valid range (> 0 and <= MAX)
+pub fn set_quest_cap(
+    env: &Env,
+    pool_id: &Symbol,
+    owner: &Address,
+    quest_id: &Symbol,
+    cap: i128,
+) -> Result<(), Error> {
+    let pool = storage::get_pool(env, pool_id)?;
+    let quest = storage::get_quest(env, quest_id)?;
+
+    if *owner != pool.owner {
+        return Err(Error::Unauthorized);
+    }
+
+    if quest.pool.as_ref() != Some(pool_id) {
+        return Err(Error::PoolNotFound);
+    }
+
+    quest::validate_quest_is_live(&quest.status)?;
+    validation::validate_reward_amount(cap)?;
+
+    let mut allowance = storage::get_pool_allowance(env, pool_id, quest_id)?;
+    let previous_cap = allowance.cap;
+    allowance.cap = cap;
+    storage::set_pool_allowance(env, pool_id, quest_id, &allowance);
+
+    // EMIT EVENT: PoolCapSet
+    events::pool_cap_set(env, pool_id.clone(), quest_id.clone(), previous_cap, cap);
+
+    Ok(())
+}
+
+/// Move one reward from a quest's pool into its escrow ahead of a claim.
+///
+/// Fails with `PoolExhausted` when the pool balance or the quest's cap
+/// cannot cover the reward.
+pub fn draw_for_claim(env: &Env, pool_id: &Symbol, quest: &Quest) -> Result<(), Error> {
+    let mut pool = storage::get_pool(env, pool_id)?;
+    let mut allowance = storage::get_pool_allowance(env, pool_id, &quest.id)?;
+    let amount = quest.reward_amount;
+
+    if pool.balance < amount || allowance.cap - allowance.spent < amount {
+        return Err(Error::PoolExhausted);
+    }
+
+    pool.balance -= amount;
+    allowance.spent += amount;
+    storage::set_pool(env, &pool);
+    storage::set_pool_allowance(env, pool_id, &quest.id, &allowance);
+
+    payout::credit_escrow_from_pool(env, &quest.id, &pool.asset, amount);
+
+    Ok(())
+}
@@ -1,17 +1,25 @@
+use crate::admin;
 use crate::errors::Error;
 use crate::events;
+use crate::payout;
+use crate::pool;
+use crate::reputation;
 use crate::storage;
-use crate::types::{Quest, QuestStatus};
+use crate::types::{Badge, Quest, QuestKind, QuestStatus, RepeatPolicy, RewardLeg, RewardTier, RewardStrategy, Sponsorship, VestingSchedule};
 use crate::validation;
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
 
 /// Register a new quest with full input validation.
 ///
 /// Validates:
 /// - Quest does not already exist
-/// - Reward amount is within valid range (> 0 and <= MAX)
+/// - Reward asset is allowlisted
+/// - Reward amount is within the asset's min/max bounds
 /// - Deadline is in the future
 /// - Creator and verifier are distinct addresses
+/// - Max claims is within valid range (> 0 and <= MAX)
+/// - The pool, if any, exists and pays in the reward asset
+#[allow(clippy::too_many_arguments)]
 pub fn register_quest(
     env: &Env,
     id: &Symbol,
@@ -20,6 +28,8 @@ pub fn register_quest(
     reward_amount: i128,
     verifier: &Address,
     deadline: u64,
+    max_claims: u32,
+    pool: Option<Symbol>,
 ) -> Result<(), Error> {
     // Validate quest ID symbol length
     validation::validate_symbol_length(id)?;
@@ -29,8 +39,9 @@ pub fn register_quest(
         return Err(Error::QuestAlreadyExists);
     }
 
-    // Validate reward amount range (min/max)
-    validation::validate_reward_amount(reward_amount)?;
+    // Validate reward asset is allowlisted and the amount is within its bounds
+    let asset_config = storage::get_asset_config(env, reward_asset)?;
+    validation::validate_reward_for_asset(reward_amount, &asset_config)?;
 
     // Validate deadline is in the future
     validation::validate_deadline(env, deadline)?;
@@ -38,7 +49,10 @@ pub fn register_quest(
     // Validate creator and verifier are different addresses
     validation::validate_addresses_distinct(creator, verifier)?;
 
-    let quest = Quest {
+    // Validate the participant cap
+    validation::validate_max_claims(max_claims)?;
+
+    let mut quest = Quest {
         id: id.clone(),
         creator: creator.clone(),
         reward_asset: reward_asset.clone(),
@@ -47,8 +61,26 @@ pub fn register_quest(
         deadline,
         status: QuestStatus::Active,
         total_claims: 0,
+        max_claims,
+        pending_claims: 0,
+        vesting_locked: 0,
+        extra_rewards: Vec::new(env),
+        funded: false,
+        pool: None,
+        strategy: RewardStrategy::Escrow,
+        mint_cap: 0,
+        minted: 0,
+        kind: QuestKind::Standard,
+        prerequisites: Vec::new(env),
+        min_level: 0,
+        required_badges: Vec::new(env),
     };
 
+    // Pool-backed quests draw their rewards at claim time instead of being funded
+    if let Some(pool_id) = pool {
+        pool::attach_quest(env, &pool_id, &mut quest)?;
+    }
+
     storage::set_quest(env, id, &quest);
 
     // EMIT EVENT: QuestRegistered
@@ -64,3 +96,912 @@ pub fn register_quest(
 
     Ok(())
 }
+
+/// Fund a quest by escrowing its full reward budget from the creator.
+///
+/// Pulls `amount * max_claims` of the primary reward asset (less what
+/// sponsors already contributed) and of every bundled reward leg from the
+/// creator into an escrow tracked for this quest only.
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is currently Active
+/// - Quest has not been funded already and neither draws from a pool nor mints
+pub fn fund_quest(env: &Env, id: &Symbol, creator: &Address) -> Result<i128, Error> {
+    let quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    validation::validate_quest_is_active(&quest.status)?;
+
+    // Pool-backed and minting quests source rewards at claim time
+    if is_funded(&quest) || has_external_source(&quest) {
+        return Err(Error::AlreadyFunded);
+    }
+
+    let budget = primary_budget(env, &quest)?;
+
+    // Sponsors may already have covered part of the budget
+    let amount = (budget - storage::get_quest_escrow(env, id, &quest.reward_asset)).max(0);
+    if amount > 0 {
+        deposit_primary(env, &quest, creator, amount)?;
+    }
+
+    // EMIT EVENT: QuestFunded
+    events::quest_funded(
+        env,
+        id.clone(),
+        creator.clone(),
+        quest.reward_asset.clone(),
+        amount,
+    );
+
+    for leg in quest.extra_rewards.iter() {
+        let leg_amount = leg
+            .amount
+            .checked_mul(quest.max_claims as i128)
+            .ok_or(Error::AmountTooLarge)?;
+        payout::deposit_escrow(env, id, &leg.asset, creator, leg_amount)?;
+        events::quest_funded(env, id.clone(), creator.clone(), leg.asset, leg_amount);
+    }
+
+    let mut quest = quest;
+    quest.funded = true;
+    storage::set_quest(env, id, &quest);
+
+    Ok(amount)
+}
+
+/// Contribute to the primary reward escrow of a quest as a sponsor.
+///
+/// Any address may sponsor a live quest. Contributions are recorded so
+/// cancellation and expiry refunds are split pro-rata between sponsors.
+///
+/// Validates:
+/// - Quest exists and is Active or Paused
+/// - Amount is within valid range (> 0 and <= MAX) and funds at least one claim
+/// - The quest has fewer than MAX_SPONSORS sponsors, or the sponsor already contributed
+pub fn sponsor_quest(env: &Env, id: &Symbol, sponsor: &Address, amount: i128) -> Result<i128, Error> {
+    let quest = storage::get_quest(env, id)?;
+
+    validate_quest_is_live(&quest.status)?;
+    validation::validate_reward_amount(amount)?;
+    if has_external_source(&quest) {
+        return Err(Error::AlreadyFunded);
+    }
+    // Dust sponsorships would let anyone fill the sponsor list cheaply
+    if amount < quest.reward_amount {
+        return Err(Error::InvalidRewardAmount);
+    }
+
+    deposit_primary(env, &quest, sponsor, amount)?;
+    let escrow = storage::get_quest_escrow(env, id, &quest.reward_asset);
+
+    // EMIT EVENT: QuestSponsored
+    events::quest_sponsored(env, id.clone(), sponsor.clone(), quest.reward_asset, amount, escrow);
+
+    Ok(escrow)
+}
+
+/// Cancel a quest and refund its unspent escrow to the creator.
+///
+/// Already-approved submissions keep their reward reserved in escrow and
+/// can still be claimed until the claim window closes, after which
+/// `reclaim_expired` returns what was never claimed; everything above that
+/// reservation is refunded now,
+/// for the primary reward and every bundled leg. The primary refund is split
+/// pro-rata between sponsors and is the returned amount.
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest status transition (Active/Paused -> Cancelled) is valid
+pub fn cancel_quest(env: &Env, id: &Symbol, creator: &Address) -> Result<i128, Error> {
+    let quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    validation::validate_quest_status_transition(&quest.status, &QuestStatus::Cancelled)?;
+
+    storage::update_quest_status(env, id, QuestStatus::Cancelled)?;
+
+    // Unranked contest entries can never claim, so nothing is owed to them
+    let claimable = if quest.kind == QuestKind::Contest {
+        0
+    } else {
+        quest.pending_claims
+    };
+
+    // Keep enough in escrow for approved-but-unclaimed submissions and vesting rewards
+    let owed = if tracks_reserved(env, id) {
+        storage::get_reserved_rewards(env, id)
+    } else {
+        quest
+            .reward_amount
+            .checked_mul(claimable as i128)
+            .ok_or(Error::AmountTooLarge)?
+    };
+    let reserved = owed + quest.vesting_locked;
+    let escrow = storage::get_quest_escrow(env, id, &quest.reward_asset);
+    let refund = (escrow - reserved).max(0);
+
+    if refund > 0 {
+        refund_primary(env, &quest, refund)?;
+    }
+
+    // EMIT EVENT: QuestCancelled
+    events::quest_cancelled(env, id.clone(), creator.clone(), quest.reward_asset, refund);
+
+    for leg in quest.extra_rewards.iter() {
+        let reserved = leg
+            .amount
+            .checked_mul(claimable as i128)
+            .ok_or(Error::AmountTooLarge)?;
+        let leg_refund = (storage::get_quest_escrow(env, id, &leg.asset) - reserved).max(0);
+        if leg_refund > 0 {
+            payout::refund_escrow(env, id, &leg.asset, creator, leg_refund)?;
+            events::quest_cancelled(env, id.clone(), creator.clone(), leg.asset, leg_refund);
+        }
+    }
+
+    Ok(refund)
+}
+
+/// Sweep an expired quest once its claim window has closed.
+///
+/// Also sweeps completed and cancelled quests, whose escrow may still hold
+/// rewards reserved for hunters who never claimed. Marks the quest Expired,
+/// voids approved-but-unclaimed submissions and returns remaining escrow not
+/// locked in vesting positions to the creator and sponsors, pro-rata to
+/// their contributions. Anyone may trigger the
+/// sweep since funds can only flow back to `quest.creator`.
+///
+/// Validates:
+/// - Quest exists
+/// - Claim window after the deadline has closed
+/// - Quest status transition (Active/Paused/Completed/Cancelled -> Expired) is valid
+pub fn reclaim_expired(env: &Env, id: &Symbol) -> Result<i128, Error> {
+    let quest = storage::get_quest(env, id)?;
+
+    validation::validate_claim_window_closed(
+        env,
+        quest.deadline,
+        storage::get_claim_window_seconds(env),
+    )?;
+
+    validation::validate_quest_status_transition(&quest.status, &QuestStatus::Expired)?;
+
+    storage::expire_quest(env, id)?;
+
+    // Rewards already claimed into vesting positions still belong to their hunters
+    let escrow = storage::get_quest_escrow(env, id, &quest.reward_asset);
+    let refund = (escrow - quest.vesting_locked).max(0);
+    if refund > 0 {
+        refund_primary(env, &quest, refund)?;
+    }
+
+    // EMIT EVENT: QuestExpired
+    events::quest_expired(env, id.clone(), quest.creator.clone(), quest.reward_asset, refund);
+
+    for leg in quest.extra_rewards.iter() {
+        let leg_refund = storage::get_quest_escrow(env, id, &leg.asset);
+        if leg_refund > 0 {
+            payout::refund_escrow(env, id, &leg.asset, &quest.creator, leg_refund)?;
+            events::quest_expired(env, id.clone(), quest.creator.clone(), leg.asset, leg_refund);
+        }
+    }
+
+    Ok(refund)
+}
+
+/// Set the claim window applied after quest deadlines (admin only)
+pub fn set_claim_window(env: &Env, caller: &Address, seconds: u64) -> Result<(), Error> {
+    admin::require_admin(env, caller)?;
+    storage::set_claim_window_seconds(env, seconds);
+    Ok(())
+}
+
+/// Deposit additional reward budget into the escrow of a live quest.
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is Active or Paused
+/// - Amount is within valid range (> 0 and <= MAX)
+pub fn top_up_quest(env: &Env, id: &Symbol, creator: &Address, amount: i128) -> Result<i128, Error> {
+    let quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    validate_quest_is_live(&quest.status)?;
+    validation::validate_reward_amount(amount)?;
+
+    deposit_primary(env, &quest, creator, amount)?;
+    let escrow = storage::get_quest_escrow(env, id, &quest.reward_asset);
+
+    // EMIT EVENT: QuestToppedUp
+    events::quest_topped_up(
+        env,
+        id.clone(),
+        creator.clone(),
+        quest.reward_asset,
+        amount,
+        escrow,
+    );
+
+    Ok(escrow)
+}
+
+/// Raise the per-claim reward of a live quest.
+///
+/// For a funded quest the creator deposits the difference for every claim
+/// not yet paid (`(new - old) * (max_claims - total_claims)`), so approved
+/// and future claims stay fully covered. An unfunded quest only records the
+/// new amount, which `fund_quest` will escrow in full.
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is Active or Paused
+/// - Quest is neither a contest, tiered nor staged, whose amounts are fixed up front
+/// - New amount is within the asset's bounds and greater than the current reward
+pub fn increase_reward(
+    env: &Env,
+    id: &Symbol,
+    creator: &Address,
+    new_amount: i128,
+) -> Result<i128, Error> {
+    let mut quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    validate_quest_is_live(&quest.status)?;
+
+    if quest.kind == QuestKind::Contest || tracks_reserved(env, id) {
+        return Err(Error::QuestTermsLocked);
+    }
+
+    // Asset removed from the allowlist since registration: bound by the global range
+    match storage::get_asset_config(env, &quest.reward_asset) {
+        Ok(config) => validation::validate_reward_for_asset(new_amount, &config)?,
+        Err(_) => validation::validate_reward_amount(new_amount)?,
+    }
+
+    // Rewards only go up: approved submissions were promised at least the current amount
+    if new_amount <= quest.reward_amount {
+        return Err(Error::InvalidRewardAmount);
+    }
+
+    let old_amount = quest.reward_amount;
+    let mut deposited = 0;
+    if is_funded(&quest) {
+        let remaining_claims = quest.max_claims.saturating_sub(quest.total_claims);
+        deposited = (new_amount - old_amount)
+            .checked_mul(remaining_claims as i128)
+            .ok_or(Error::AmountTooLarge)?;
+        if deposited > 0 {
+            deposit_primary(env, &quest, creator, deposited)?;
+        }
+    }
+
+    quest.reward_amount = new_amount;
+    storage::set_quest(env, id, &quest);
+
+    // EMIT EVENT: RewardIncreased
+    events::reward_increased(
+        env,
+        id.clone(),
+        creator.clone(),
+        old_amount,
+        new_amount,
+        deposited,
+    );
+
+    Ok(deposited)
+}
+
+/// Amend the parameters of a live quest (creator only).
+///
+/// Each provided value is validated and applied independently, and every
+/// applied change emits a `quest_amended` event with its old and new value.
+/// Raising `max_claims` on a funded quest escrows the extra budget for the
+/// primary reward and every bundled leg.
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is Active or Paused
+/// - New deadline is in the future and later than the current one
+/// - New verifier is distinct from the creator
+/// - New max claims is within range and greater than the current cap; bounties,
+///   contests and tiered quests keep the cap set up front
+pub fn amend_quest(
+    env: &Env,
+    id: &Symbol,
+    creator: &Address,
+    new_deadline: Option<u64>,
+    new_verifier: Option<Address>,
+    new_max_claims: Option<u32>,
+) -> Result<(), Error> {
+    let mut quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    validate_quest_is_live(&quest.status)?;
+
+    if let Some(deadline) = new_deadline {
+        validation::validate_deadline_extension(env, quest.deadline, deadline)?;
+        events::quest_amended(env, id.clone(), symbol_short!("deadline"), quest.deadline, deadline);
+        quest.deadline = deadline;
+    }
+
+    if let Some(verifier) = new_verifier {
+        validation::validate_addresses_distinct(creator, &verifier)?;
+        events::quest_amended(
+            env,
+            id.clone(),
+            symbol_short!("verifier"),
+            quest.verifier.clone(),
+            verifier.clone(),
+        );
+        quest.verifier = verifier;
+    }
+
+    if let Some(max_claims) = new_max_claims {
+        validation::validate_max_claims(max_claims)?;
+        if max_claims <= quest.max_claims
+            || quest.kind != QuestKind::Standard
+            || !storage::get_reward_tiers(env, id).is_empty()
+        {
+            return Err(Error::InvalidMaxClaims);
+        }
+
+        if is_funded(&quest) {
+            let added_claims = (max_claims - quest.max_claims) as i128;
+            let extra = quest
+                .reward_amount
+                .checked_mul(added_claims)
+                .ok_or(Error::AmountTooLarge)?;
+            deposit_primary(env, &quest, creator, extra)?;
+
+            for leg in quest.extra_rewards.iter() {
+                let leg_extra = leg.amount.checked_mul(added_claims).ok_or(Error::AmountTooLarge)?;
+                payout::deposit_escrow(env, id, &leg.asset, creator, leg_extra)?;
+            }
+        }
+
+        events::quest_amended(
+            env,
+            id.clone(),
+            symbol_short!("max_claim"),
+            quest.max_claims,
+            max_claims,
+        );
+        quest.max_claims = max_claims;
+    }
+
+    storage::set_quest(env, id, &quest);
+
+    Ok(())
+}
+
+/// Replace the extra reward legs paid alongside the primary reward (creator only).
+///
+/// Legs are always paid at claim time, even when the primary reward vests.
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is Active or Paused
+/// - Quest has not been funded and neither draws from a pool nor mints, so
+///   the escrow matches the bundle
+/// - At most MAX_REWARD_LEGS legs
+/// - Every leg asset is allowlisted, distinct from the primary asset and
+///   the other legs, with an amount within the asset's bounds
+pub fn set_reward_bundle(
+    env: &Env,
+    id: &Symbol,
+    creator: &Address,
+    legs: Vec<RewardLeg>,
+) -> Result<(), Error> {
+    let mut quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    validate_quest_is_live(&quest.status)?;
+
+    if is_funded(&quest) || has_external_source(&quest) {
+        return Err(Error::AlreadyFunded);
+    }
+
+    if legs.len() > validation::MAX_REWARD_LEGS {
+        return Err(Error::ArrayTooLong);
+    }
+
+    let mut seen: Vec<Address> = Vec::new(env);
+    seen.push_back(quest.reward_asset.clone());
+    for leg in legs.iter() {
+        if seen.contains(&leg.asset) {
+            return Err(Error::InvalidAsset);
+        }
+        let config = storage::get_asset_config(env, &leg.asset)?;
+        validation::validate_reward_for_asset(leg.amount, &config)?;
+        seen.push_back(leg.asset);
+    }
+
+    quest.extra_rewards = legs;
+    storage::set_quest(env, id, &quest);
+
+    Ok(())
+}
+
+/// Configure or remove the vesting schedule of a quest (creator only).
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is Active or Paused
+/// - No submission has been approved yet, so hunters never see terms change
+/// - Schedule has at least one tranche, tranches fit in the duration and
+///   the cliff does not exceed the duration
+pub fn set_vesting(
+    env: &Env,
+    id: &Symbol,
+    creator: &Address,
+    schedule: Option<VestingSchedule>,
+) -> Result<(), Error> {
+    let quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    validate_quest_is_live(&quest.status)?;
+
+    if quest.total_claims > 0 || quest.pending_claims > 0 {
+        return Err(Error::QuestTermsLocked);
+    }
+
+    match schedule {
+        Some(schedule) => {
+            // Vesting locks rewards in escrow, which minting quests never hold;
+            // staged and repeatable quests pay several times into one position
+            if quest.strategy == RewardStrategy::Mint
+                || !storage::get_milestones(env, id).is_empty()
+                || storage::get_repeat_policy(env, id).is_some()
+            {
+                return Err(Error::InvalidVestingSchedule);
+            }
+            validation::validate_vesting_schedule(&schedule)?;
+            storage::set_quest_vesting(env, id, &schedule);
+        }
+        None => storage::remove_quest_vesting(env, id),
+    }
+
+    Ok(())
+}
+
+/// Switch a quest to minting rewards on claim instead of paying from escrow.
+///
+/// `mint_cap` bounds the total gross amount the quest may ever mint.
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is Active or Paused
+/// - Caller is the issuer an admin enabled minting for on the reward asset
+/// - Quest holds no escrow, has no pool, bundle or vesting schedule
+/// - Mint cap is within valid range (> 0 and <= MAX)
+pub fn set_mint_mode(env: &Env, id: &Symbol, creator: &Address, mint_cap: i128) -> Result<(), Error> {
+    let mut quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    validate_quest_is_live(&quest.status)?;
+
+    if storage::get_asset_issuer(env, &quest.reward_asset) != Some(creator.clone()) {
+        return Err(Error::NotMintable);
+    }
+
+    let has_escrow = storage::get_quest_escrow(env, id, &quest.reward_asset) > 0;
+    if is_funded(&quest)
+        || has_escrow
+        || quest.pool.is_some()
+        || !quest.extra_rewards.is_empty()
+        || storage::get_quest_vesting(env, id).is_some()
+    {
+        return Err(Error::NotMintable);
+    }
+
+    validation::validate_reward_amount(mint_cap)?;
+
+    quest.strategy = RewardStrategy::Mint;
+    quest.mint_cap = mint_cap;
+    storage::set_quest(env, id, &quest);
+
+    Ok(())
+}
+
+/// Reserve a quest for proven contributors (creator only).
+///
+/// Hunters need at least `min_level` and every badge in `required_badges`;
+/// a level of 0 and no badges open the quest to everyone again.
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is Active or Paused
+/// - Nobody has submitted proof yet
+/// - Level is reachable and no more badges are required than exist
+pub fn set_reputation_gate(
+    env: &Env,
+    id: &Symbol,
+    creator: &Address,
+    min_level: u32,
+    required_badges: Vec<Badge>,
+) -> Result<(), Error> {
+    let mut quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    validate_quest_is_live(&quest.status)?;
+
+    if storage::has_submitters(env, id) {
+        return Err(Error::QuestTermsLocked);
+    }
+
+    if min_level > reputation::MAX_LEVEL {
+        return Err(Error::InvalidLevel);
+    }
+    if required_badges.len() > reputation::BADGE_COUNT {
+        return Err(Error::ArrayTooLong);
+    }
+
+    quest.min_level = min_level;
+    quest.required_badges = required_badges;
+    storage::set_quest(env, id, &quest);
+
+    Ok(())
+}
+
+/// Configure or remove the repeat policy of a quest (creator only).
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is Active or Paused
+/// - No submission has been approved yet, so hunters never see terms change
+/// - Quest is a standard quest without milestones or vesting
+/// - Completion cap is between 1 and MAX_QUEST_CLAIMS
+pub fn set_repeat_policy(
+    env: &Env,
+    id: &Symbol,
+    creator: &Address,
+    policy: Option<RepeatPolicy>,
+) -> Result<(), Error> {
+    let quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    validate_quest_is_live(&quest.status)?;
+
+    if quest.total_claims > 0 || quest.pending_claims > 0 {
+        return Err(Error::QuestTermsLocked);
+    }
+
+    match policy {
+        Some(policy) => {
+            if quest.kind != QuestKind::Standard
+                || !storage::get_milestones(env, id).is_empty()
+                || storage::get_quest_vesting(env, id).is_some()
+            {
+                return Err(Error::QuestTermsLocked);
+            }
+            validation::validate_max_claims(policy.max_completions)?;
+            storage::set_repeat_policy(env, id, &policy);
+        }
+        None => storage::remove_repeat_policy(env, id),
+    }
+
+    Ok(())
+}
+
+/// Turn a quest into a single-winner bounty picked with `select_winner`.
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is Active or Paused
+/// - Quest pays a single claim, so its budget matches one winner
+/// - Nobody has submitted proof yet and the quest has no reward tiers,
+///   milestones or repeat policy
+pub fn set_bounty_mode(env: &Env, id: &Symbol, creator: &Address) -> Result<(), Error> {
+    let mut quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    validate_quest_is_live(&quest.status)?;
+
+    if quest.max_claims != 1 {
+        return Err(Error::InvalidMaxClaims);
+    }
+
+    if storage::has_submitters(env, id)
+        || tracks_reserved(env, id)
+        || storage::get_repeat_policy(env, id).is_some()
+    {
+        return Err(Error::QuestTermsLocked);
+    }
+
+    quest.kind = QuestKind::Bounty;
+    storage::set_quest(env, id, &quest);
+
+    Ok(())
+}
+
+/// Turn a quest into a ranked contest paying `prizes[i]` to rank `i + 1`.
+///
+/// The quest's cap becomes one claim per prize and its reward amount the
+/// top prize; `fund_quest` escrows the prize total.
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is Active or Paused
+/// - Quest has not been funded yet, has no reward tiers, milestones or
+///   repeat policy and no submission has been approved
+/// - Between 1 and MAX_PRIZE_RANKS prizes, each within the asset's bounds
+pub fn set_prize_table(env: &Env, id: &Symbol, creator: &Address, prizes: Vec<i128>) -> Result<(), Error> {
+    let mut quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    validate_quest_is_live(&quest.status)?;
+
+    if is_funded(&quest) {
+        return Err(Error::AlreadyFunded);
+    }
+
+    if quest.pending_claims > 0
+        || quest.total_claims > 0
+        || tracks_reserved(env, id)
+        || storage::get_repeat_policy(env, id).is_some()
+    {
+        return Err(Error::QuestTermsLocked);
+    }
+
+    if prizes.is_empty() {
+        return Err(Error::InvalidMaxClaims);
+    }
+    if prizes.len() > validation::MAX_PRIZE_RANKS {
+        return Err(Error::ArrayTooLong);
+    }
+
+    let config = storage::get_asset_config(env, &quest.reward_asset)?;
+    for prize in prizes.iter() {
+        validation::validate_reward_for_asset(prize, &config)?;
+    }
+
+    quest.kind = QuestKind::Contest;
+    quest.max_claims = prizes.len();
+    quest.reward_amount = prizes.get(0).unwrap();
+    storage::set_quest(env, id, &quest);
+    storage::set_prize_table(env, id, &prizes);
+
+    Ok(())
+}
+
+/// Define early-bird reward tiers by approval order (creator only).
+///
+/// The quest's cap becomes the total tier count and its reward amount the
+/// first tier's; `fund_quest` escrows the sum of every tier.
+///
+/// Validates:
+/// - Quest exists and caller is the creator
+/// - Quest is Active or Paused, neither a bounty, a contest nor staged
+/// - Quest has not been funded yet and no submission has been approved
+/// - Between 1 and MAX_REWARD_TIERS tiers, each covering at least one
+///   approval with an amount within the asset's bounds
+/// - Total tier count is within the max claims range
+pub fn set_reward_tiers(
+    env: &Env,
+    id: &Symbol,
+    creator: &Address,
+    tiers: Vec<RewardTier>,
+) -> Result<(), Error> {
+    let mut quest = storage::get_quest(env, id)?;
+
+    if *creator != quest.creator {
+        return Err(Error::Unauthorized);
+    }
+
+    validate_quest_is_live(&quest.status)?;
+
+    if is_funded(&quest) {
+        return Err(Error::AlreadyFunded);
+    }
+
+    if quest.kind != QuestKind::Standard
+        || quest.pending_claims > 0
+        || quest.total_claims > 0
+        || !storage::get_milestones(env, id).is_empty()
+    {
+        return Err(Error::QuestTermsLocked);
+    }
+
+    if tiers.is_empty() {
+        return Err(Error::InvalidMaxClaims);
+    }
+    if tiers.len() > validation::MAX_REWARD_TIERS {
+        return Err(Error::ArrayTooLong);
+    }
+
+    let config = storage::get_asset_config(env, &quest.reward_asset)?;
+    let mut max_claims: u32 = 0;
+    for tier in tiers.iter() {
+        validation::validate_reward_for_asset(tier.amount, &config)?;
+        if tier.count == 0 {
+            return Err(Error::InvalidMaxClaims);
+        }
+        max_claims = max_claims.checked_add(tier.count).ok_or(Error::InvalidMaxClaims)?;
+    }
+    validation::validate_max_claims(max_claims)?;
+
+    quest.max_claims = max_claims;
+    quest.reward_amount = tiers.get(0).unwrap().amount;
+    storage::set_quest(env, id, &quest);
+    storage::set_reward_tiers(env, id, &tiers);
+
+    Ok(())
+}
+
+/// Find the tier of the approval at 0-based position `index`.
+///
+/// Returns the tier index and its amount, or `None` once every tier is used up.
+pub(crate) fn tier_for_approval(tiers: &Vec<RewardTier>, index: u32) -> Option<(u32, i128)> {
+    let mut end: u32 = 0;
+    for (i, tier) in tiers.iter().enumerate() {
+        end = end.saturating_add(tier.count);
+        if index < end {
+            return Some((i as u32, tier.amount));
+        }
+    }
+    None
+}
+
+/// Resolve what a claim pays: the submitter's prize on contests, their
+/// early-bird tier amount on tiered quests, else the flat reward.
+///
+/// Contest entries can only claim once ranked by `finalize_ranking`.
+pub fn claim_amount(env: &Env, quest: &Quest, submitter: &Address) -> Result<i128, Error> {
+    if quest.kind == QuestKind::Contest {
+        let rank = storage::get_contest_rank(env, &quest.id, submitter)?;
+        return storage::get_prize_table(env, &quest.id)
+            .get(rank - 1)
+            .ok_or(Error::NotRanked);
+    }
+
+    let tiers = storage::get_reward_tiers(env, &quest.id);
+    if tiers.is_empty() {
+        return Ok(quest.reward_amount);
+    }
+
+    let submission = storage::get_submission(env, &quest.id, submitter)?;
+    tiers
+        .get(submission.tier)
+        .map(|tier| tier.amount)
+        .ok_or(Error::InvalidSubmissionStatus)
+}
+
+/// Count a reward against the mint cap of a quest before minting it.
+pub fn record_mint(env: &Env, id: &Symbol, amount: i128) -> Result<(), Error> {
+    let mut quest = storage::get_quest(env, id)?;
+
+    if quest.minted + amount > quest.mint_cap {
+        return Err(Error::MintCapExceeded);
+    }
+
+    quest.minted += amount;
+    storage::set_quest(env, id, &quest);
+    Ok(())
+}
+
+/// Pool-backed and minting quests source rewards at claim time.
+fn has_external_source(quest: &Quest) -> bool {
+    quest.pool.is_some() || quest.strategy == RewardStrategy::Mint
+}
+
+/// Primary reward budget: the prize total for contests, the tier total for
+/// tiered quests, else one reward per claim.
+fn primary_budget(env: &Env, quest: &Quest) -> Result<i128, Error> {
+    if quest.kind == QuestKind::Contest {
+        return Ok(storage::get_prize_table(env, &quest.id).iter().sum());
+    }
+
+    let tiers = storage::get_reward_tiers(env, &quest.id);
+    if !tiers.is_empty() {
+        let mut budget: i128 = 0;
+        for tier in tiers.iter() {
+            let tier_total = tier
+                .amount
+                .checked_mul(tier.count as i128)
+                .ok_or(Error::AmountTooLarge)?;
+            budget = budget.checked_add(tier_total).ok_or(Error::AmountTooLarge)?;
+        }
+        return Ok(budget);
+    }
+
+    quest
+        .reward_amount
+        .checked_mul(quest.max_claims as i128)
+        .ok_or(Error::AmountTooLarge)
+}
+
+/// Tiered and staged quests track what approved submissions are owed.
+pub(crate) fn tracks_reserved(env: &Env, id: &Symbol) -> bool {
+    !storage::get_reward_tiers(env, id).is_empty() || !storage::get_milestones(env, id).is_empty()
+}
+
+/// A quest is funded once its creator has escrowed the full budget.
+fn is_funded(quest: &Quest) -> bool {
+    quest.funded
+}
+
+/// Deposit into the primary reward escrow and record the contribution.
+///
+/// The creator never takes one of the MAX_SPONSORS slots, so third-party
+/// sponsors cannot lock the creator out of funding their own quest.
+fn deposit_primary(env: &Env, quest: &Quest, from: &Address, amount: i128) -> Result<(), Error> {
+    payout::deposit_escrow(env, &quest.id, &quest.reward_asset, from, amount)?;
+    let capped = *from != quest.creator;
+    storage::add_sponsor_contribution(env, &quest.id, from, amount, capped)
+}
+
+/// Refund primary reward escrow to its sponsors, pro-rata to their contributions.
+///
+/// Rounding dust goes to the last sponsor so the full amount is returned.
+/// Quests without recorded contributions refund the creator.
+pub(crate) fn refund_primary(env: &Env, quest: &Quest, amount: i128) -> Result<(), Error> {
+    let sponsors = storage::get_quest_sponsors(env, &quest.id);
+    if sponsors.is_empty() {
+        return payout::refund_escrow(env, &quest.id, &quest.reward_asset, &quest.creator, amount);
+    }
+
+    let total: i128 = sponsors.iter().map(|s: Sponsorship| s.amount).sum();
+    let mut remaining = amount;
+    let last = sponsors.len() - 1;
+
+    for (i, entry) in sponsors.iter().enumerate() {
+        let share = if i as u32 == last {
+            remaining
+        } else {
+            amount * entry.amount / total
+        };
+        if share > 0 {
+            payout::refund_escrow(env, &quest.id, &quest.reward_asset, &entry.sponsor, share)?;
+            events::sponsor_refunded(env, quest.id.clone(), entry.sponsor, quest.reward_asset.clone(), share);
+            remaining -= share;
+        }
+    }
+
+    Ok(())
+}
+
+/// Quests can only be changed while Active or Paused.
+pub(crate) fn validate_quest_is_live(status: &QuestStatus) -> Result<(), Error> {
+    if *status != QuestStatus::Active && *status != QuestStatus::Paused {
+        return Err(Error::QuestNotActive);
+    }
+    Ok(())
+}
@@ -2,7 +2,7 @@ use crate::admin;
 use crate::errors::Error;
 use crate::events;
 use crate::storage;
-use crate::types::{Badge, UserStats};
+use crate::types::{Badge, Quest, UserStats};
 use soroban_sdk::{Address, Env};
 
 const LEVEL_2_XP: u64 = 300;
@@ -10,6 +10,12 @@ const LEVEL_3_XP: u64 = 600;
 const LEVEL_4_XP: u64 = 1000;
 const LEVEL_5_XP: u64 = 1500;
 
+/// Highest level `calculate_level` returns
+pub const MAX_LEVEL: u32 = 5;
+
+/// Number of distinct badges
+pub const BADGE_COUNT: u32 = 5;
+
 /// Award XP to a user upon quest completion
 pub fn award_xp(env: &Env, user: &Address, xp_amount: u64) -> Result<UserStats, Error> {
     let mut stats = storage::get_user_stats_or_default(env, user);
@@ -35,7 +41,7 @@ pub fn award_xp(env: &Env, user: &Address, xp_amount: u64) -> Result<UserStats,
 /// Calculate user level based on total XP
 pub fn calculate_level(xp: u64) -> u32 {
     if xp >= LEVEL_5_XP {
-        5
+        MAX_LEVEL
     } else if xp >= LEVEL_4_XP {
         4
     } else if xp >= LEVEL_3_XP {
@@ -66,3 +72,22 @@ pub fn grant_badge(env: &Env, caller: &Address, user: &Address, badge: Badge) ->
 pub fn get_user_stats(env: &Env, user: &Address) -> UserStats {
     storage::get_user_stats_or_default(env, user)
 }
+
+/// Ensure a user has the level and badges a quest requires
+pub fn require_reputation(env: &Env, quest: &Quest, user: &Address) -> Result<(), Error> {
+    if quest.min_level == 0 && quest.required_badges.is_empty() {
+        return Ok(());
+    }
+
+    let stats = get_user_stats(env, user);
+    if stats.level < quest.min_level {
+        return Err(Error::PrerequisiteNotMet);
+    }
+    for badge in quest.required_badges.iter() {
+        if !stats.badges.contains(&badge) {
+            return Err(Error::PrerequisiteNotMet);
+        }
+    }
+
+    Ok(())
+}
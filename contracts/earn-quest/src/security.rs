@@ -1,7 +1,10 @@
 use crate::errors::Error;
 use crate::events;
+use crate::payout;
 use crate::storage;
-use soroban_sdk::{Address, Env, token};
+use crate::types::{PayoutLimit, QuestStatus, WithdrawOverride};
+use crate::validation::{MAX_OVERRIDE_QUESTS, MAX_PAYOUT_BUCKETS, OVERRIDE_APPROVAL_SECONDS};
+use soroban_sdk::{Address, Env, Symbol, Vec, token};
 
 /// Is contract paused?
 pub fn is_paused(env: &Env) -> bool {
@@ -15,6 +18,11 @@ pub fn require_not_paused(env: &Env) -> Result<(), Error> {
     Ok(())
 }
 
+/// Has the circuit breaker paused an asset's payouts?
+pub fn is_payouts_paused(env: &Env, asset: &Address) -> bool {
+    storage::is_payouts_paused(env, asset)
+}
+
 /// Pause the contract immediately (admin only)
 pub fn emergency_pause(env: &Env, caller: &Address) -> Result<(), Error> {
     caller.require_auth();
@@ -64,24 +72,123 @@ pub fn emergency_unpause(env: &Env, caller: &Address) -> Result<(), Error> {
         return Err(Error::Unauthorized);
     }
 
-    let scheduled = storage::get_scheduled_unpause_time(env);
-    if scheduled.is_none() {
-        return Err(Error::InsufficientApprovals);
+    require_unpause_scheduled(env)?;
+
+    storage::set_paused(env, false);
+    storage::clear_unpause_approvals(env);
+    events::emergency_unpaused(env, caller.clone());
+    Ok(())
+}
+
+/// Resume an asset's payouts after the circuit breaker tripped. Goes through
+/// the same approvals and timelock as `emergency_unpause` and consumes them,
+/// but leaves the manual contract pause alone.
+pub fn resume_payouts(env: &Env, caller: &Address, asset: &Address) -> Result<(), Error> {
+    caller.require_auth();
+
+    if !storage::is_admin(env, caller) {
+        return Err(Error::Unauthorized);
     }
 
-    let now = env.ledger().timestamp();
-    let ts = scheduled.unwrap();
-    if now < ts {
+    require_unpause_scheduled(env)?;
+
+    // The window starts afresh so held-back claims can be paid
+    storage::set_payouts_paused(env, asset, false);
+    storage::clear_payout_log(env, asset);
+    storage::clear_unpause_approvals(env);
+    events::payouts_resumed(env, caller.clone(), asset.clone());
+    Ok(())
+}
+
+/// Have enough admins approved an unpause and has its timelock expired?
+fn require_unpause_scheduled(env: &Env) -> Result<(), Error> {
+    let scheduled = match storage::get_scheduled_unpause_time(env) {
+        Some(ts) => ts,
+        None => return Err(Error::InsufficientApprovals),
+    };
+
+    if env.ledger().timestamp() < scheduled {
         return Err(Error::TimelockNotExpired);
     }
+    Ok(())
+}
 
-    storage::set_paused(env, false);
-    storage::clear_unpause_approvals(env);
-    events::emergency_unpaused(env, caller.clone());
+/// Approve an emergency withdrawal of `amount` of `asset` to `to` that dips
+/// into escrowed liabilities. The escrow the withdrawal takes comes out of the
+/// listed `quests`, which are written off and cancelled when it goes through.
+/// Approvals only count towards that exact withdrawal; approving different
+/// terms, or approving after the pending override expired, starts a new one.
+/// The override is active once approvals meet the unpause threshold and is
+/// consumed by the matching withdrawal.
+pub fn emergency_approve_override(
+    env: &Env,
+    caller: &Address,
+    asset: &Address,
+    to: &Address,
+    amount: i128,
+    quests: &Vec<Symbol>,
+) -> Result<(), Error> {
+    caller.require_auth();
+
+    if !storage::is_admin(env, caller) {
+        return Err(Error::Unauthorized);
+    }
+
+    if amount <= 0 {
+        return Err(Error::InvalidRewardAmount);
+    }
+
+    if quests.is_empty() {
+        return Err(Error::QuestNotFound);
+    }
+    if quests.len() > MAX_OVERRIDE_QUESTS {
+        return Err(Error::ArrayTooLong);
+    }
+    for quest_id in quests.iter() {
+        if !storage::has_quest(env, &quest_id) {
+            return Err(Error::QuestNotFound);
+        }
+    }
+
+    let now = env.ledger().timestamp();
+    let mut pending = match storage::get_withdraw_override(env) {
+        Some(pending) if pending.expires_at > now && covers(&pending, asset, to, amount, quests) => pending,
+        _ => WithdrawOverride {
+            asset: asset.clone(),
+            to: to.clone(),
+            amount,
+            quests: quests.clone(),
+            expires_at: now + OVERRIDE_APPROVAL_SECONDS,
+            approvals: Vec::new(env),
+        },
+    };
+    if pending.approvals.contains(caller) {
+        return Err(Error::AlreadyApproved);
+    }
+
+    pending.approvals.push_back(caller.clone());
+    storage::set_withdraw_override(env, &pending);
+    events::withdraw_override_approved(env, caller.clone(), asset.clone(), to.clone(), amount);
     Ok(())
 }
 
-/// Emergency withdrawal when paused (admin only)
+/// Is the pending override for exactly this withdrawal?
+fn covers(
+    pending: &WithdrawOverride,
+    asset: &Address,
+    to: &Address,
+    amount: i128,
+    quests: &Vec<Symbol>,
+) -> bool {
+    pending.asset == *asset && pending.to == *to && pending.amount == amount && pending.quests == *quests
+}
+
+/// Emergency withdrawal when paused (admin only).
+/// Withdrawals are limited to the surplus above escrowed liabilities unless
+/// an override for the same asset, amount and recipient has been approved by
+/// enough admins. The override's quests have their escrow zeroed and are
+/// cancelled, and only that written-off escrow may be taken on top of the
+/// surplus, so no other quest's deposit can be spent by a later claim.
 pub fn emergency_withdraw(
     env: &Env,
     caller: &Address,
@@ -106,15 +213,56 @@ pub fn emergency_withdraw(
     let token_client = token::Client::new(env, asset);
     let contract_address = env.current_contract_address();
 
-    let balance = token_client.balance(&contract_address);
-    if balance < amount {
+    let report = payout::reconcile(env, asset);
+    if report.balance < amount {
         return Err(Error::InsufficientBalance);
     }
 
+    // Dipping into escrowed funds needs a separately approved override
+    let uses_override = amount > report.surplus;
+    let mut written_off: i128 = 0;
+    if uses_override {
+        let now = env.ledger().timestamp();
+        let pending = match storage::get_withdraw_override(env) {
+            Some(pending)
+                if pending.expires_at > now
+                    && pending.asset == *asset
+                    && pending.to == *to
+                    && pending.amount == amount
+                    && pending.approvals.len() >= storage::get_unpause_threshold(env) =>
+            {
+                pending
+            }
+            _ => return Err(Error::ExceedsSurplus),
+        };
+
+        // Write off the named quests so their escrow can no longer be claimed
+        for quest_id in pending.quests.iter() {
+            let escrow = storage::get_quest_escrow(env, &quest_id, asset);
+            if escrow > 0 {
+                storage::set_quest_escrow(env, &quest_id, asset, 0);
+                events::escrow_written_off(env, quest_id.clone(), asset.clone(), escrow);
+                written_off += escrow;
+            }
+            let quest = storage::get_quest(env, &quest_id)?;
+            if quest.status == QuestStatus::Active || quest.status == QuestStatus::Paused {
+                storage::update_quest_status(env, &quest_id, QuestStatus::Cancelled)?;
+            }
+        }
+
+        if amount > report.surplus.max(0) + written_off {
+            return Err(Error::ExceedsSurplus);
+        }
+    }
+
     let transfer_result = token_client.try_transfer(&contract_address, to, &amount);
 
     match transfer_result {
         Ok(Ok(_)) => {
+            if uses_override {
+                storage::clear_withdraw_override(env);
+                storage::adjust_liabilities(env, asset, -written_off);
+            }
             events::emergency_withdrawn(env, caller.clone(), asset.clone(), to.clone(), amount);
             Ok(())
         }
@@ -141,3 +289,120 @@ pub fn set_unpause_timelock(env: &Env, caller: &Address, seconds: u64) -> Result
     storage::set_unpause_timelock_seconds(env, seconds);
     Ok(())
 }
+
+/// Admins can cap the payout volume of an allowlisted asset per rolling window.
+/// A `max_amount` of zero removes the limit. A limit must cover at least the
+/// asset's largest reward, so every single claim can be paid.
+pub fn set_payout_limit(
+    env: &Env,
+    caller: &Address,
+    asset: &Address,
+    max_amount: i128,
+    window_seconds: u64,
+) -> Result<(), Error> {
+    caller.require_auth();
+    if !storage::is_admin(env, caller) {
+        return Err(Error::Unauthorized);
+    }
+
+    if max_amount == 0 {
+        storage::remove_payout_limit(env, asset);
+    } else {
+        if max_amount < 0 || window_seconds == 0 {
+            return Err(Error::InvalidRewardAmount);
+        }
+        if max_amount < storage::get_asset_config(env, asset)?.max_reward {
+            return Err(Error::InvalidRewardAmount);
+        }
+        let limit = PayoutLimit {
+            max_amount,
+            window_seconds,
+        };
+        storage::set_payout_limit(env, asset, &limit);
+    }
+
+    events::payout_limit_set(env, caller.clone(), asset.clone(), max_amount, window_seconds);
+    Ok(())
+}
+
+/// Amount paid out of an asset within its current rolling window
+pub fn window_volume(env: &Env, asset: &Address) -> i128 {
+    match storage::get_payout_limit(env, asset) {
+        Some(limit) => live_payouts(env, asset, &limit).iter().map(|(_, amount)| amount).sum(),
+        None => 0,
+    }
+}
+
+/// Record a claim's payouts against their assets' limits.
+///
+/// Fails with `PayoutsPaused` if the breaker has already paused one of the
+/// assets. Returns `Ok(false)` without recording anything when any asset
+/// would exceed its limit: that asset's payouts are then paused until the
+/// unpause approvals resume them, so the caller must return `Ok` without
+/// paying for the pause and its alert to persist.
+pub fn record_payouts(env: &Env, payouts: &Vec<(Address, i128)>) -> Result<bool, Error> {
+    for (asset, _) in payouts.iter() {
+        if storage::is_payouts_paused(env, &asset) {
+            return Err(Error::PayoutsPaused);
+        }
+    }
+
+    // Check every asset first so a partial claim is never recorded
+    for (asset, _) in payouts.iter() {
+        let Some(limit) = storage::get_payout_limit(env, &asset) else {
+            continue;
+        };
+        let paid: i128 = live_payouts(env, &asset, &limit).iter().map(|(_, amount)| amount).sum();
+        let attempted: i128 = payouts
+            .iter()
+            .filter(|(other, _)| *other == asset)
+            .map(|(_, amount)| amount)
+            .sum();
+
+        if paid.saturating_add(attempted) > limit.max_amount {
+            storage::set_payouts_paused(env, &asset, true);
+            events::circuit_tripped(env, asset.clone(), attempted, paid, limit.max_amount);
+            return Ok(false);
+        }
+    }
+
+    let now = env.ledger().timestamp();
+    for (asset, amount) in payouts.iter() {
+        let Some(limit) = storage::get_payout_limit(env, &asset) else {
+            continue;
+        };
+        // Payouts are summed per bucket so the log keeps at most MAX_PAYOUT_BUCKETS + 1
+        let bucket = now - now % bucket_seconds(&limit);
+        let mut log = live_payouts(env, &asset, &limit);
+        match log.last() {
+            Some((start, total)) if start == bucket => log.set(log.len() - 1, (start, total + amount)),
+            _ => log.push_back((bucket, amount)),
+        }
+        storage::set_payout_log(env, &asset, &log);
+    }
+
+    Ok(true)
+}
+
+/// Length of one payout log bucket
+fn bucket_seconds(limit: &PayoutLimit) -> u64 {
+    (limit.window_seconds / MAX_PAYOUT_BUCKETS as u64).max(1)
+}
+
+/// Payout log buckets still inside the rolling window.
+///
+/// The window rolls in steps of one bucket (a 24th of its length): a bucket
+/// is dropped once its end lies `window_seconds` in the past. A payout thus
+/// counts for at least the full window and at most one bucket longer, so the
+/// limit holds over every span of `window_seconds`.
+fn live_payouts(env: &Env, asset: &Address, limit: &PayoutLimit) -> Vec<(u64, i128)> {
+    let now = env.ledger().timestamp();
+    let horizon = bucket_seconds(limit).saturating_add(limit.window_seconds);
+    let mut live = Vec::new(env);
+    for (ts, amount) in storage::get_payout_log(env, asset).iter() {
+        if ts.saturating_add(horizon) > now {
+            live.push_back((ts, amount));
+        }
+    }
+    live
+}
@@ -1,6 +1,10 @@
 use crate::errors::Error;
-use crate::types::{Quest, QuestStatus, Submission, SubmissionStatus, UserStats};
-use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+use crate::types::{
+    AssetConfig, HoldingRule, Milestone, PayoutLimit, RepeatPolicy, TeamMember, PoolAllowance, Quest, RewardTier, QuestStatus, Sponsorship, Submission, SubmissionStatus, UserStats,
+    RewardPool, VestingPosition, VestingSchedule, WithdrawOverride,
+};
+use crate::validation::{DEFAULT_CLAIM_WINDOW_SECONDS, MAX_SPONSORS};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Symbol, Vec};
 
 /// Storage key definitions for the contract's persistent data.
 ///
@@ -28,6 +32,70 @@ pub enum DataKey {
     UnpauseTimelockSeconds,
     /// Scheduled unpause ledger timestamp
     ScheduledUnpauseTime,
+    /// Remaining escrowed reward budget, keyed by quest ID and reward asset address
+    QuestEscrow(Symbol, Address),
+    /// Seconds after a quest deadline during which approved rewards can be claimed
+    ClaimWindowSeconds,
+    /// Contributions to a quest's primary reward escrow, keyed by quest ID
+    QuestSponsors(Symbol),
+    /// Total escrow owed to quests, keyed by reward asset address
+    Liabilities(Address),
+    /// Pending emergency withdrawal below liabilities and the admins approving it
+    WithdrawOverride,
+    /// Reward bounds of an allowlisted asset, keyed by asset address
+    AssetConfig(Address),
+    /// List of allowlisted reward assets
+    AllowedAssets,
+    /// Address allowed to create mint-on-claim quests for an asset, keyed by asset address
+    AssetIssuer(Address),
+    /// Platform fee in basis points deducted from every payout
+    FeeBps,
+    /// Address receiving platform fees
+    Treasury,
+    /// Stores the vesting schedule of a quest, keyed by quest ID
+    QuestVesting(Symbol),
+    /// Stores a vesting reward position, keyed by quest ID and beneficiary address
+    Vesting(Symbol, Address),
+    /// Stores a shared reward pool, keyed by pool ID
+    RewardPool(Symbol),
+    /// Budget a quest may draw from a pool, keyed by pool ID and quest ID
+    PoolAllowance(Symbol, Symbol),
+    /// Max payout volume per rolling window, keyed by reward asset address
+    PayoutLimit(Address),
+    /// Payouts inside the current window summed per bucket start, keyed by reward asset address
+    PayoutLog(Address),
+    /// Payouts halted by the circuit breaker, keyed by reward asset address
+    PayoutsPaused(Address),
+    /// Addresses that entered a bounty, keyed by quest ID
+    QuestSubmitters(Symbol),
+    /// Number of distinct addresses that submitted proof to a quest, keyed by quest ID
+    SubmitterCount(Symbol),
+    /// Prize amounts of a contest by rank, keyed by quest ID
+    PrizeTable(Symbol),
+    /// 1-based rank assigned to a contest winner, keyed by quest ID and submitter address
+    ContestRank(Symbol, Address),
+    /// Early-bird reward tiers of a quest in approval order, keyed by quest ID
+    RewardTiers(Symbol),
+    /// Tier and milestone rewards approved but not yet claimed, keyed by quest ID
+    ReservedRewards(Symbol),
+    /// Ordered milestones of a staged quest, keyed by quest ID
+    Milestones(Symbol),
+    /// Proof of one milestone, keyed by quest ID, submitter address and milestone index
+    MilestoneSubmission(Symbol, Address, u32),
+    /// Members sharing a team submission's reward, keyed by quest ID and lead address
+    Team(Symbol, Address),
+    /// Lead of the team a member joined, keyed by quest ID and member address
+    TeamLead(Symbol, Address),
+    /// Cooldown and per-user completion cap of a repeatable quest, keyed by quest ID
+    RepeatPolicy(Symbol),
+    /// Merkle root of the addresses allowed to submit proof, keyed by quest ID
+    AllowlistRoot(Symbol),
+    /// Token balances a hunter must hold to submit proof, keyed by quest ID
+    HoldingRules(Symbol),
+    /// Completed earlier attempt of a repeatable quest, keyed by quest ID, submitter address and attempt number
+    SubmissionAttempt(Symbol, Address, u32),
+    /// Set once a claim completing a quest paid an address, solo or as a team member
+    QuestCompleted(Symbol, Address),
 }
 
 //================================================================================
@@ -224,6 +292,1365 @@ pub fn set_user_stats(env: &Env, user: &Address, stats: &UserStats) {
         .set(&DataKey::UserStats(user.clone()), stats);
 }
 
+//================================================================================
+// Escrow Storage Functions
+//================================================================================
+
+/// Checks if a quest has been funded (an escrow entry exists).
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `asset` - The reward asset address
+///
+/// # Returns
+/// * `true` if the quest has an escrow entry, `false` otherwise
+///
+/// # Storage Access
+/// * Reads from: Instance storage (existence check only)
+/// * Gas Cost: Low
+///
+/// # Notes
+/// * An escrow drained to zero by payouts still counts as funded
+pub fn has_quest_escrow(env: &Env, quest_id: &Symbol, asset: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::QuestEscrow(quest_id.clone(), asset.clone()))
+}
+
+/// Retrieves the remaining escrowed reward budget of a quest in one asset.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `asset` - The reward asset address
+///
+/// # Returns
+/// * The escrowed amount, or `0` if the quest was never funded
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Low
+pub fn get_quest_escrow(env: &Env, quest_id: &Symbol, asset: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::QuestEscrow(quest_id.clone(), asset.clone()))
+        .unwrap_or(0)
+}
+
+/// Stores the remaining escrowed reward budget of a quest in one asset.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `asset` - The reward asset address
+/// * `amount` - The new escrow balance
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Low
+///
+/// # Notes
+/// * Callers are responsible for moving the matching tokens (see `payout` module)
+pub fn set_quest_escrow(env: &Env, quest_id: &Symbol, asset: &Address, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::QuestEscrow(quest_id.clone(), asset.clone()), &amount);
+}
+
+/// Retrieves every contribution to the primary reward escrow of a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+///
+/// # Returns
+/// * The sponsorships in order of first contribution, empty if never funded
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Moderate
+pub fn get_quest_sponsors(env: &Env, quest_id: &Symbol) -> Vec<Sponsorship> {
+    env.storage()
+        .instance()
+        .get(&DataKey::QuestSponsors(quest_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Records a contribution to the primary reward escrow of a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `sponsor` - The contributing address (the creator counts as a sponsor)
+/// * `amount` - The deposited amount
+/// * `capped` - Whether a new sponsor takes one of the MAX_SPONSORS slots
+///
+/// # Returns
+/// * `Ok(())` - If the contribution was recorded
+/// * `Err(Error::ArrayTooLong)` - If a new capped sponsor would exceed MAX_SPONSORS
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Writes to: Instance storage
+/// * Gas Cost: Moderate
+pub fn add_sponsor_contribution(
+    env: &Env,
+    quest_id: &Symbol,
+    sponsor: &Address,
+    amount: i128,
+    capped: bool,
+) -> Result<(), Error> {
+    let mut sponsors = get_quest_sponsors(env, quest_id);
+
+    let existing = sponsors.iter().position(|s| s.sponsor == *sponsor);
+    match existing {
+        Some(index) => {
+            let mut entry = sponsors.get(index as u32).unwrap();
+            entry.amount += amount;
+            sponsors.set(index as u32, entry);
+        }
+        None => {
+            if capped && sponsors.len() >= MAX_SPONSORS {
+                return Err(Error::ArrayTooLong);
+            }
+            sponsors.push_back(Sponsorship {
+                sponsor: sponsor.clone(),
+                amount,
+            });
+        }
+    }
+
+    env.storage()
+        .instance()
+        .set(&DataKey::QuestSponsors(quest_id.clone()), &sponsors);
+    Ok(())
+}
+
+/// Retrieves the total escrow owed to quests in a reward asset.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `asset` - The reward asset address
+///
+/// # Returns
+/// * The outstanding liabilities, or `0` if none were ever recorded
+///
+/// # Notes
+/// * Approved-but-unclaimed rewards stay in escrow, so they are included
+pub fn get_liabilities(env: &Env, asset: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::Liabilities(asset.clone()))
+        .unwrap_or(0)
+}
+
+/// Adjusts the total escrow owed to quests in a reward asset.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `asset` - The reward asset address
+/// * `delta` - Positive when escrow is deposited, negative when it is released
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Writes to: Instance storage
+/// * Gas Cost: Low
+pub fn adjust_liabilities(env: &Env, asset: &Address, delta: i128) {
+    let current = get_liabilities(env, asset);
+    env.storage()
+        .instance()
+        .set(&DataKey::Liabilities(asset.clone()), &(current + delta));
+}
+
+/// Sets the claim window applied after every quest deadline.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `seconds` - Seconds after the deadline during which approved rewards can be claimed
+pub fn set_claim_window_seconds(env: &Env, seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ClaimWindowSeconds, &seconds);
+}
+
+/// Gets the claim window applied after every quest deadline.
+///
+/// # Returns
+/// * The configured window, or `DEFAULT_CLAIM_WINDOW_SECONDS` if never set
+pub fn get_claim_window_seconds(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ClaimWindowSeconds)
+        .unwrap_or(DEFAULT_CLAIM_WINDOW_SECONDS)
+}
+
+//================================================================================
+// Asset Allowlist Storage Functions
+//================================================================================
+
+/// Retrieves the allowlist entry of a reward asset.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `asset` - The reward asset address
+///
+/// # Returns
+/// * `Ok(AssetConfig)` - The reward bounds if the asset is allowlisted
+/// * `Err(Error::InvalidAsset)` - If the asset is not allowlisted
+pub fn get_asset_config(env: &Env, asset: &Address) -> Result<AssetConfig, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::AssetConfig(asset.clone()))
+        .ok_or(Error::InvalidAsset)
+}
+
+/// Adds an asset to the allowlist or updates its reward bounds.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `asset` - The reward asset address
+/// * `config` - The validated reward bounds
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Writes to: Instance storage
+/// * Gas Cost: Moderate
+pub fn set_asset_config(env: &Env, asset: &Address, config: &AssetConfig) {
+    let mut assets = get_allowed_assets(env);
+    if !assets.contains(asset) {
+        assets.push_back(asset.clone());
+        env.storage().instance().set(&DataKey::AllowedAssets, &assets);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::AssetConfig(asset.clone()), config);
+}
+
+/// Removes an asset from the allowlist.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `asset` - The reward asset address
+///
+/// # Returns
+/// * `Ok(())` - If the asset was removed
+/// * `Err(Error::InvalidAsset)` - If the asset was not allowlisted
+///
+/// # Notes
+/// * Existing quests in the asset keep paying out; only new quests are blocked
+pub fn remove_asset_config(env: &Env, asset: &Address) -> Result<(), Error> {
+    let mut assets = get_allowed_assets(env);
+    let index = assets.first_index_of(asset).ok_or(Error::InvalidAsset)?;
+    assets.remove(index);
+    env.storage().instance().set(&DataKey::AllowedAssets, &assets);
+    env.storage()
+        .instance()
+        .remove(&DataKey::AssetConfig(asset.clone()));
+    Ok(())
+}
+
+/// Lists all allowlisted reward assets.
+///
+/// # Returns
+/// * The allowlisted asset addresses, empty if none
+pub fn get_allowed_assets(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::AllowedAssets)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Gets the issuer allowed to create mint-on-claim quests for an asset.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `asset` - The reward asset address
+///
+/// # Returns
+/// * `Some(Address)` - If minting was enabled for the asset
+/// * `None` - Otherwise
+pub fn get_asset_issuer(env: &Env, asset: &Address) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::AssetIssuer(asset.clone()))
+}
+
+/// Sets the issuer allowed to create mint-on-claim quests for an asset.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `asset` - The reward asset address
+/// * `issuer` - The address allowed to enable minting on its quests
+pub fn set_asset_issuer(env: &Env, asset: &Address, issuer: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AssetIssuer(asset.clone()), issuer);
+}
+
+//================================================================================
+// Platform Fee Storage Functions
+//================================================================================
+
+/// Sets the platform fee and the treasury receiving it.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `fee_bps` - The validated fee in basis points
+/// * `treasury` - The address receiving fees
+pub fn set_platform_fee(env: &Env, fee_bps: u32, treasury: &Address) {
+    env.storage().instance().set(&DataKey::FeeBps, &fee_bps);
+    env.storage().instance().set(&DataKey::Treasury, treasury);
+}
+
+/// Gets the platform fee in basis points.
+///
+/// # Returns
+/// * The configured fee, or 0 if never set
+pub fn get_fee_bps(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::FeeBps).unwrap_or(0)
+}
+
+/// Gets the treasury address receiving platform fees.
+///
+/// # Returns
+/// * `Some(Address)` - If a fee has been configured
+/// * `None` - Otherwise
+pub fn get_treasury(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Treasury)
+}
+
+//================================================================================
+// Vesting Storage Functions
+//================================================================================
+
+/// Retrieves the vesting schedule of a quest, if any.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `id` - The quest identifier
+///
+/// # Returns
+/// * `Some(VestingSchedule)` - If rewards of the quest vest
+/// * `None` - If rewards are paid out at claim time
+pub fn get_quest_vesting(env: &Env, id: &Symbol) -> Option<VestingSchedule> {
+    env.storage().instance().get(&DataKey::QuestVesting(id.clone()))
+}
+
+/// Stores the vesting schedule of a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `id` - The quest identifier
+/// * `schedule` - The validated vesting schedule
+pub fn set_quest_vesting(env: &Env, id: &Symbol, schedule: &VestingSchedule) {
+    env.storage()
+        .instance()
+        .set(&DataKey::QuestVesting(id.clone()), schedule);
+}
+
+/// Removes the vesting schedule of a quest so rewards are paid at claim time.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `id` - The quest identifier
+pub fn remove_quest_vesting(env: &Env, id: &Symbol) {
+    env.storage()
+        .instance()
+        .remove(&DataKey::QuestVesting(id.clone()));
+}
+
+/// Retrieves the vesting position of a beneficiary for a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `beneficiary` - The address receiving the vested reward
+///
+/// # Returns
+/// * `Ok(VestingPosition)` - The position if found
+/// * `Err(Error::VestingNotFound)` - If the reward was not claimed with vesting
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Moderate
+pub fn get_vesting_position(
+    env: &Env,
+    quest_id: &Symbol,
+    beneficiary: &Address,
+) -> Result<VestingPosition, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Vesting(quest_id.clone(), beneficiary.clone()))
+        .ok_or(Error::VestingNotFound)
+}
+
+/// Stores or updates a vesting position.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `position` - The vesting position to store
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: High
+pub fn set_vesting_position(env: &Env, position: &VestingPosition) {
+    env.storage().instance().set(
+        &DataKey::Vesting(position.quest_id.clone(), position.beneficiary.clone()),
+        position,
+    );
+}
+
+/// Adjusts the amount of a quest's escrow locked in vesting positions.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `id` - The quest identifier
+/// * `delta` - Positive when a position is created, negative on withdrawal
+///
+/// # Returns
+/// * `Ok(())` - If the update was successful
+/// * `Err(Error::QuestNotFound)` - If the quest doesn't exist
+pub fn adjust_quest_vesting_locked(env: &Env, id: &Symbol, delta: i128) -> Result<(), Error> {
+    let mut quest = get_quest(env, id)?;
+    quest.vesting_locked += delta;
+    set_quest(env, id, &quest);
+    Ok(())
+}
+
+//================================================================================
+// Reward Pool Storage Functions
+//================================================================================
+
+/// Checks if a reward pool exists.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `id` - The pool identifier
+///
+/// # Returns
+/// * `true` if the pool exists, `false` otherwise
+pub fn has_pool(env: &Env, id: &Symbol) -> bool {
+    env.storage().instance().has(&DataKey::RewardPool(id.clone()))
+}
+
+/// Retrieves a reward pool by ID.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `id` - The pool identifier
+///
+/// # Returns
+/// * `Ok(RewardPool)` - The pool if found
+/// * `Err(Error::PoolNotFound)` - If the pool doesn't exist
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Moderate
+pub fn get_pool(env: &Env, id: &Symbol) -> Result<RewardPool, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RewardPool(id.clone()))
+        .ok_or(Error::PoolNotFound)
+}
+
+/// Stores or updates a reward pool.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `pool` - The pool to store
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Moderate
+pub fn set_pool(env: &Env, pool: &RewardPool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RewardPool(pool.id.clone()), pool);
+}
+
+/// Retrieves how much a quest may draw from a pool and has drawn so far.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `pool_id` - The pool identifier
+/// * `quest_id` - The quest identifier
+///
+/// # Returns
+/// * `Ok(PoolAllowance)` - The allowance if the quest is attached to the pool
+/// * `Err(Error::PoolNotFound)` - If the quest was never attached
+pub fn get_pool_allowance(
+    env: &Env,
+    pool_id: &Symbol,
+    quest_id: &Symbol,
+) -> Result<PoolAllowance, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PoolAllowance(pool_id.clone(), quest_id.clone()))
+        .ok_or(Error::PoolNotFound)
+}
+
+/// Stores how much a quest may draw from a pool and has drawn so far.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `pool_id` - The pool identifier
+/// * `quest_id` - The quest identifier
+/// * `allowance` - The allowance to store
+pub fn set_pool_allowance(
+    env: &Env,
+    pool_id: &Symbol,
+    quest_id: &Symbol,
+    allowance: &PoolAllowance,
+) {
+    env.storage().instance().set(
+        &DataKey::PoolAllowance(pool_id.clone(), quest_id.clone()),
+        allowance,
+    );
+}
+
+//================================================================================
+// Payout Limit Storage Functions
+//================================================================================
+
+/// Retrieves the payout limit of a reward asset.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `asset` - The reward asset address
+///
+/// # Returns
+/// * `Some(PayoutLimit)` - The max amount per window and the window length
+/// * `None` - If the asset's payouts are not capped
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Low
+pub fn get_payout_limit(env: &Env, asset: &Address) -> Option<PayoutLimit> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PayoutLimit(asset.clone()))
+}
+
+/// Stores or updates the payout limit of a reward asset.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `asset` - The reward asset address
+/// * `limit` - The max amount per window and the window length
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Low
+pub fn set_payout_limit(env: &Env, asset: &Address, limit: &PayoutLimit) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PayoutLimit(asset.clone()), limit);
+}
+
+/// Removes the payout limit of a reward asset along with its payout log.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `asset` - The reward asset address
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Low
+///
+/// # Notes
+/// * A tripped breaker stays tripped; only the unpause approvals resume it
+pub fn remove_payout_limit(env: &Env, asset: &Address) {
+    env.storage()
+        .instance()
+        .remove(&DataKey::PayoutLimit(asset.clone()));
+    clear_payout_log(env, asset);
+}
+
+/// Retrieves the payouts recorded against a reward asset's limit.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `asset` - The reward asset address
+///
+/// # Returns
+/// * `Vec<(u64, i128)>` - Amounts paid summed per bucket, keyed by bucket start
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Moderate
+///
+/// # Notes
+/// * Returns an empty list if nothing was paid since the log was last cleared
+pub fn get_payout_log(env: &Env, asset: &Address) -> Vec<(u64, i128)> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PayoutLog(asset.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the payouts recorded against a reward asset's limit.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `asset` - The reward asset address
+/// * `log` - Amounts paid summed per bucket, keyed by bucket start
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Moderate
+pub fn set_payout_log(env: &Env, asset: &Address, log: &Vec<(u64, i128)>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PayoutLog(asset.clone()), log);
+}
+
+/// Clears the payouts recorded against a reward asset's limit.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `asset` - The reward asset address
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Low
+pub fn clear_payout_log(env: &Env, asset: &Address) {
+    env.storage()
+        .instance()
+        .remove(&DataKey::PayoutLog(asset.clone()));
+}
+
+/// Checks if the circuit breaker has paused a reward asset's payouts.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `asset` - The reward asset address
+///
+/// # Returns
+/// * `true` if payouts of the asset are paused, `false` otherwise
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Low
+pub fn is_payouts_paused(env: &Env, asset: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::PayoutsPaused(asset.clone()))
+}
+
+/// Sets or clears the circuit breaker's paused flag for a reward asset.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `asset` - The reward asset address
+/// * `paused` - Whether payouts of the asset are paused
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Low
+pub fn set_payouts_paused(env: &Env, asset: &Address, paused: bool) {
+    if paused {
+        env.storage()
+            .instance()
+            .set(&DataKey::PayoutsPaused(asset.clone()), &true);
+    } else {
+        env.storage()
+            .instance()
+            .remove(&DataKey::PayoutsPaused(asset.clone()));
+    }
+}
+
+//================================================================================
+// Submitter Storage Functions
+//================================================================================
+
+/// Counts the distinct addresses that submitted proof to a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+///
+/// # Returns
+/// * The number of first-time submitters, 0 if nobody has submitted yet
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Low
+pub fn get_submitter_count(env: &Env, quest_id: &Symbol) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SubmitterCount(quest_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Checks whether anybody has submitted proof to a quest, which locks its terms.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+///
+/// # Returns
+/// * `true` once a first proof has been submitted, `false` otherwise
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Low
+pub fn has_submitters(env: &Env, quest_id: &Symbol) -> bool {
+    get_submitter_count(env, quest_id) > 0
+}
+
+/// Counts a first-time submitter to a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Writes to: Instance storage
+/// * Gas Cost: Low
+///
+/// # Notes
+/// * Callers must only count addresses without an existing submission
+pub fn increment_submitter_count(env: &Env, quest_id: &Symbol) {
+    let count = get_submitter_count(env, quest_id).saturating_add(1);
+    env.storage()
+        .instance()
+        .set(&DataKey::SubmitterCount(quest_id.clone()), &count);
+}
+
+/// Retrieves the addresses that entered a bounty, in submission order.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+///
+/// # Returns
+/// * The entrant index, empty for other quest kinds or if nobody has entered yet
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Moderate (grows with the number of entrants)
+pub fn get_quest_submitters(env: &Env, quest_id: &Symbol) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::QuestSubmitters(quest_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Appends an entrant to the bounty's entrant index.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `submitter` - The address of the first-time submitter
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Writes to: Instance storage
+/// * Gas Cost: Moderate (rewrites the whole index)
+///
+/// # Notes
+/// * Callers must only add addresses without an existing submission
+/// * Only bounties are indexed, bounded by MAX_BOUNTY_SUBMISSIONS
+pub fn add_quest_submitter(env: &Env, quest_id: &Symbol, submitter: &Address) {
+    let mut submitters = get_quest_submitters(env, quest_id);
+    submitters.push_back(submitter.clone());
+    env.storage()
+        .instance()
+        .set(&DataKey::QuestSubmitters(quest_id.clone()), &submitters);
+}
+
+//================================================================================
+// Contest Storage Functions
+//================================================================================
+
+/// Retrieves the prize table of a contest, highest rank first.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+///
+/// # Returns
+/// * The prizes by rank, empty for non-contest quests
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Moderate
+pub fn get_prize_table(env: &Env, quest_id: &Symbol) -> Vec<i128> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PrizeTable(quest_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the prize table of a contest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `prizes` - The validated prizes, one per rank starting at rank 1
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Moderate
+pub fn set_prize_table(env: &Env, quest_id: &Symbol, prizes: &Vec<i128>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PrizeTable(quest_id.clone()), prizes);
+}
+
+/// Retrieves the rank assigned to a contest winner.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `submitter` - The ranked submitter's address
+///
+/// # Returns
+/// * `Ok(u32)` - The 1-based rank
+/// * `Err(Error::NotRanked)` - If the submitter was not ranked
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Low
+pub fn get_contest_rank(env: &Env, quest_id: &Symbol, submitter: &Address) -> Result<u32, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ContestRank(quest_id.clone(), submitter.clone()))
+        .ok_or(Error::NotRanked)
+}
+
+/// Records the rank of a contest winner.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `submitter` - The ranked submitter's address
+/// * `rank` - The 1-based rank into the prize table
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Low
+pub fn set_contest_rank(env: &Env, quest_id: &Symbol, submitter: &Address, rank: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ContestRank(quest_id.clone(), submitter.clone()), &rank);
+}
+
+//================================================================================
+// Reward Tier Storage Functions
+//================================================================================
+
+/// Retrieves the early-bird reward tiers of a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+///
+/// # Returns
+/// * The tiers in approval order, empty for flat-reward quests
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Moderate
+pub fn get_reward_tiers(env: &Env, quest_id: &Symbol) -> Vec<RewardTier> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RewardTiers(quest_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the early-bird reward tiers of a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `tiers` - The validated tiers in approval order
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Moderate
+pub fn set_reward_tiers(env: &Env, quest_id: &Symbol, tiers: &Vec<RewardTier>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RewardTiers(quest_id.clone()), tiers);
+}
+
+//================================================================================
+// Milestone Storage Functions
+//================================================================================
+
+/// Retrieves the milestones of a staged quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+///
+/// # Returns
+/// * The milestones in delivery order, empty for single-stage quests
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Moderate
+pub fn get_milestones(env: &Env, quest_id: &Symbol) -> Vec<Milestone> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Milestones(quest_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the milestones of a staged quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `milestones` - The validated milestones in delivery order
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Moderate
+pub fn set_milestones(env: &Env, quest_id: &Symbol, milestones: &Vec<Milestone>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Milestones(quest_id.clone()), milestones);
+}
+
+/// Retrieves the proof a submitter delivered for one milestone.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `submitter` - The address of the user who submitted
+/// * `milestone` - The milestone index
+///
+/// # Returns
+/// * `Ok(Submission)` - The milestone submission if found
+/// * `Err(Error::SubmissionNotFound)` - If the milestone was not submitted
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Moderate
+pub fn get_milestone_submission(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    milestone: u32,
+) -> Result<Submission, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::MilestoneSubmission(quest_id.clone(), submitter.clone(), milestone))
+        .ok_or(Error::SubmissionNotFound)
+}
+
+/// Stores or updates the proof of one milestone.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `milestone` - The milestone index
+/// * `submission` - The milestone submission, keyed by its quest and submitter
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: High
+pub fn set_milestone_submission(env: &Env, milestone: u32, submission: &Submission) {
+    env.storage().instance().set(
+        &DataKey::MilestoneSubmission(
+            submission.quest_id.clone(),
+            submission.submitter.clone(),
+            milestone,
+        ),
+        submission,
+    );
+}
+
+//================================================================================
+// Reserved Reward Storage Functions
+//================================================================================
+
+/// Retrieves the tier and milestone rewards owed to approved-but-unclaimed
+/// submissions of a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+///
+/// # Returns
+/// * The reserved amount, 0 if nothing is owed
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Low
+pub fn get_reserved_rewards(env: &Env, quest_id: &Symbol) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ReservedRewards(quest_id.clone()))
+        .unwrap_or(0)
+}
+
+/// Adjusts the approved rewards owed by a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `delta` - Positive on approval, negative on claim
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Writes to: Instance storage
+/// * Gas Cost: Low
+pub fn adjust_reserved_rewards(env: &Env, quest_id: &Symbol, delta: i128) {
+    let reserved = get_reserved_rewards(env, quest_id) + delta;
+    env.storage()
+        .instance()
+        .set(&DataKey::ReservedRewards(quest_id.clone()), &reserved);
+}
+
+//================================================================================
+// Team Storage Functions
+//================================================================================
+
+/// Retrieves the team sharing a lead's submission.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `lead` - The address that submitted for the team
+///
+/// # Returns
+/// * The members and their shares, empty for solo submissions
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Moderate
+pub fn get_team(env: &Env, quest_id: &Symbol, lead: &Address) -> Vec<TeamMember> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Team(quest_id.clone(), lead.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the team sharing a lead's submission.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `lead` - The address that submitted for the team
+/// * `members` - The validated members and their shares, lead included
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Moderate
+pub fn set_team(env: &Env, quest_id: &Symbol, lead: &Address, members: &Vec<TeamMember>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::Team(quest_id.clone(), lead.clone()), members);
+}
+
+/// Removes the team of a lead's submission, making it a solo submission.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `lead` - The address that submitted for the team
+///
+/// # Storage Access
+/// * Writes to: Instance storage (removal)
+/// * Gas Cost: Low
+pub fn remove_team(env: &Env, quest_id: &Symbol, lead: &Address) {
+    env.storage()
+        .instance()
+        .remove(&DataKey::Team(quest_id.clone(), lead.clone()));
+}
+
+/// Retrieves the lead of the team a member joined on a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `member` - The member's address
+///
+/// # Returns
+/// * `Some(Address)` - The lead, if the address joined someone else's team
+/// * `None` - If the address is not a team member on this quest
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Low
+pub fn get_team_lead(env: &Env, quest_id: &Symbol, member: &Address) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TeamLead(quest_id.clone(), member.clone()))
+}
+
+/// Records the lead of the team a member joined on a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `member` - The member's address
+/// * `lead` - The address that submitted for the team
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Low
+pub fn set_team_lead(env: &Env, quest_id: &Symbol, member: &Address, lead: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TeamLead(quest_id.clone(), member.clone()), lead);
+}
+
+/// Removes a member from the team index of a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `member` - The member's address
+///
+/// # Storage Access
+/// * Writes to: Instance storage (removal)
+/// * Gas Cost: Low
+pub fn remove_team_lead(env: &Env, quest_id: &Symbol, member: &Address) {
+    env.storage()
+        .instance()
+        .remove(&DataKey::TeamLead(quest_id.clone(), member.clone()));
+}
+
+//================================================================================
+// Repeat Policy Storage Functions
+//================================================================================
+
+/// Retrieves the repeat policy of a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+///
+/// # Returns
+/// * `Some(RepeatPolicy)` - If the quest is repeatable
+/// * `None` - If each user can complete the quest once
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Low
+pub fn get_repeat_policy(env: &Env, quest_id: &Symbol) -> Option<RepeatPolicy> {
+    env.storage()
+        .instance()
+        .get(&DataKey::RepeatPolicy(quest_id.clone()))
+}
+
+/// Stores the repeat policy of a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `policy` - The validated cooldown and completion cap
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Low
+pub fn set_repeat_policy(env: &Env, quest_id: &Symbol, policy: &RepeatPolicy) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RepeatPolicy(quest_id.clone()), policy);
+}
+
+/// Removes the repeat policy of a quest so each user completes it once.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+///
+/// # Storage Access
+/// * Writes to: Instance storage (removal)
+/// * Gas Cost: Low
+pub fn remove_repeat_policy(env: &Env, quest_id: &Symbol) {
+    env.storage()
+        .instance()
+        .remove(&DataKey::RepeatPolicy(quest_id.clone()));
+}
+
+/// Retrieves a completed earlier attempt of a repeatable quest.
+///
+/// The current attempt always lives under `DataKey::Submission`.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `submitter` - The address of the user who submitted
+/// * `attempt` - The 1-based attempt number
+///
+/// # Returns
+/// * `Ok(Submission)` - The archived attempt if found
+/// * `Err(Error::SubmissionNotFound)` - If the attempt was never archived
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Moderate
+pub fn get_submission_attempt(
+    env: &Env,
+    quest_id: &Symbol,
+    submitter: &Address,
+    attempt: u32,
+) -> Result<Submission, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::SubmissionAttempt(quest_id.clone(), submitter.clone(), attempt))
+        .ok_or(Error::SubmissionNotFound)
+}
+
+/// Archives a completed attempt of a repeatable quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `submission` - The paid submission, keyed by its quest, submitter and attempt
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: High
+pub fn set_submission_attempt(env: &Env, submission: &Submission) {
+    env.storage().instance().set(
+        &DataKey::SubmissionAttempt(
+            submission.quest_id.clone(),
+            submission.submitter.clone(),
+            submission.attempt,
+        ),
+        submission,
+    );
+}
+
+//================================================================================
+// Quest Allowlist Storage Functions
+//================================================================================
+
+/// Retrieves the Merkle root of a quest's allowlist.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+///
+/// # Returns
+/// * `Some(BytesN<32>)` - If only listed addresses may submit proof
+/// * `None` - If the quest is open to everyone
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Low
+pub fn get_allowlist_root(env: &Env, quest_id: &Symbol) -> Option<BytesN<32>> {
+    env.storage()
+        .instance()
+        .get(&DataKey::AllowlistRoot(quest_id.clone()))
+}
+
+/// Stores or rotates the Merkle root of a quest's allowlist.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `root` - The root of the tree of listed addresses
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Low
+pub fn set_allowlist_root(env: &Env, quest_id: &Symbol, root: &BytesN<32>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AllowlistRoot(quest_id.clone()), root);
+}
+
+/// Removes a quest's allowlist, opening it to everyone.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+///
+/// # Storage Access
+/// * Writes to: Instance storage (removal)
+/// * Gas Cost: Low
+pub fn remove_allowlist_root(env: &Env, quest_id: &Symbol) {
+    env.storage()
+        .instance()
+        .remove(&DataKey::AllowlistRoot(quest_id.clone()));
+}
+
+//================================================================================
+// Holding Rule Storage Functions
+//================================================================================
+
+/// Retrieves the token-holding rules of a quest.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+///
+/// # Returns
+/// * The rules every submitter must meet, empty for ungated quests
+///
+/// # Storage Access
+/// * Reads from: Instance storage
+/// * Gas Cost: Moderate
+pub fn get_holding_rules(env: &Env, quest_id: &Symbol) -> Vec<HoldingRule> {
+    env.storage()
+        .instance()
+        .get(&DataKey::HoldingRules(quest_id.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores the token-holding rules of a quest; an empty list removes the gate.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `rules` - The validated rules
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Moderate
+pub fn set_holding_rules(env: &Env, quest_id: &Symbol, rules: &Vec<HoldingRule>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::HoldingRules(quest_id.clone()), rules);
+}
+
+//================================================================================
+// Quest Completion Storage Functions
+//================================================================================
+
+/// Checks whether a completing claim of a quest ever paid an address.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `user` - The address paid solo or as a team member
+///
+/// # Returns
+/// * `true` if the user completed the quest at least once, `false` otherwise
+///
+/// # Storage Access
+/// * Reads from: Instance storage (existence check only)
+/// * Gas Cost: Low
+pub fn has_completed_quest(env: &Env, quest_id: &Symbol, user: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::QuestCompleted(quest_id.clone(), user.clone()))
+}
+
+/// Records that a completing claim of a quest paid an address.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `quest_id` - The quest identifier
+/// * `user` - The address paid solo or as a team member
+///
+/// # Storage Access
+/// * Writes to: Instance storage
+/// * Gas Cost: Low
+pub fn record_quest_completion(env: &Env, quest_id: &Symbol, user: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::QuestCompleted(quest_id.clone(), user.clone()), &true);
+}
+
 //================================================================================
 // Deletion Utilities
 //================================================================================
@@ -245,13 +1672,13 @@ pub fn set_user_stats(env: &Env, user: &Address, stats: &UserStats) {
 /// * Gas Cost: Moderate (read + delete)
 ///
 /// # Safety
-/// * Only allows deletion of Completed or Expired quests
+/// * Only allows deletion of Completed, Expired or Cancelled quests
 /// * Prevents accidental deletion of active quests
 /// * Does not cascade delete submissions (handle separately if needed)
 pub fn delete_quest(env: &Env, id: &Symbol) -> Result<(), Error> {
     let quest = get_quest(env, id)?;
 
-    // Safety check: only allow deletion of completed/expired quests
+    // Safety check: only allow deletion of completed/expired/cancelled quests
     if quest.status == QuestStatus::Active || quest.status == QuestStatus::Paused {
         return Err(Error::QuestStillActive);
     }
@@ -330,8 +1757,58 @@ pub fn update_quest_status(env: &Env, id: &Symbol, status: QuestStatus) -> Resul
     Ok(())
 }
 
+/// Atomically increments the pending_claims counter for a quest.
+///
+/// Called when a submission is approved; the claim stays pending until paid.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `id` - The quest identifier
+///
+/// # Returns
+/// * `Ok(())` - If the increment was successful
+/// * `Err(Error::QuestNotFound)` - If the quest doesn't exist
+///
+/// # Storage Access
+/// * Reads from: Instance storage (full quest read)
+/// * Writes to: Instance storage (full quest write)
+/// * Gas Cost: High
+pub fn increment_quest_pending_claims(env: &Env, id: &Symbol) -> Result<(), Error> {
+    let mut quest = get_quest(env, id)?;
+    quest.pending_claims += 1;
+    set_quest(env, id, &quest);
+    Ok(())
+}
+
+/// Marks a quest Expired and drops its pending claims.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `id` - The quest identifier
+///
+/// # Returns
+/// * `Ok(())` - If the update was successful
+/// * `Err(Error::QuestNotFound)` - If the quest doesn't exist
+///
+/// # Storage Access
+/// * Reads from: Instance storage (full quest read)
+/// * Writes to: Instance storage (full quest write)
+/// * Gas Cost: High
+///
+/// # Notes
+/// * Approved-but-unpaid submissions become void; claims on Expired quests are rejected
+pub fn expire_quest(env: &Env, id: &Symbol) -> Result<(), Error> {
+    let mut quest = get_quest(env, id)?;
+    quest.status = QuestStatus::Expired;
+    quest.pending_claims = 0;
+    set_quest(env, id, &quest);
+    Ok(())
+}
+
 /// Atomically increments the total_claims counter for a quest.
 ///
+/// Also releases one pending claim, since a paid claim is no longer outstanding.
+///
 /// # Arguments
 /// * `env` - The contract environment
 /// * `id` - The quest identifier
@@ -352,6 +1829,7 @@ pub fn update_quest_status(env: &Env, id: &Symbol, status: QuestStatus) -> Resul
 pub fn increment_quest_claims(env: &Env, id: &Symbol) -> Result<(), Error> {
     let mut quest = get_quest(env, id)?;
     quest.total_claims += 1;
+    quest.pending_claims = quest.pending_claims.saturating_sub(1);
     set_quest(env, id, &quest);
     Ok(())
 }
@@ -650,8 +2128,31 @@ fn dec_unpause_approval_count(env: &Env) {
         .instance()
         .get(&DataKey::UnpauseApprovalCount)
         .unwrap_or(0u32);
-    if cur > 0 {
-        cur -= 1;
-    }
+    cur = cur.saturating_sub(1);
     env.storage().instance().set(&DataKey::UnpauseApprovalCount, &cur);
 }
+
+pub fn get_withdraw_override(env: &Env) -> Option<WithdrawOverride> {
+    env.storage().instance().get(&DataKey::WithdrawOverride)
+}
+
+pub fn set_withdraw_override(env: &Env, pending: &WithdrawOverride) {
+    env.storage()
+        .instance()
+        .set(&DataKey::WithdrawOverride, pending);
+}
+
+pub fn clear_withdraw_override(env: &Env) {
+    env.storage().instance().remove(&DataKey::WithdrawOverride);
+}
+
+/// Drop an admin's approval of the pending withdrawal override, if any
+pub fn remove_withdraw_override_approval(env: &Env, admin: &Address) {
+    let Some(mut pending) = get_withdraw_override(env) else {
+        return;
+    };
+    if let Some(index) = pending.approvals.first_index_of(admin) {
+        pending.approvals.remove(index);
+        set_withdraw_override(env, &pending);
+    }
+}
@@ -1,9 +1,16 @@
+use crate::allowlist;
 use crate::errors::Error;
+use crate::chain;
+use crate::eligibility;
 use crate::events;
+use crate::milestone;
+use crate::payout;
+use crate::quest;
+use crate::reputation;
 use crate::storage;
-use crate::types::{Submission, SubmissionStatus};
+use crate::types::{QuestKind, QuestStatus, Submission, SubmissionStatus};
 use crate::validation;
-use soroban_sdk::{Address, BytesN, Env, Symbol};
+use soroban_sdk::{Address, BytesN, Env, Symbol, Vec};
 
 /// Submit proof for a quest with full input validation.
 ///
@@ -11,11 +18,24 @@ use soroban_sdk::{Address, BytesN, Env, Symbol};
 /// - Quest exists
 /// - Quest is currently Active
 /// - Quest has not expired (deadline not passed)
+/// - Submitter is on the quest's allowlist, if it has one
+/// - Submitter has not joined another lead's team on the quest
+/// - Submitter has been paid for every prerequisite quest and has the
+///   required level and badges
+/// - Submitter holds the minimum balance of every token the quest requires
+/// - Bounty quests have room for another submitter
+/// - Milestone index is 0 on single-stage quests, where only a pending
+///   proof can be replaced; staged quests deliver milestones in order
+///   (see `milestone::submit_proof`)
+/// - On repeatable quests a paid proof starts a new attempt once the
+///   cooldown since it has passed and the completion cap allows it
 pub fn submit_proof(
     env: &Env,
     quest_id: &Symbol,
     submitter: &Address,
+    milestone: u32,
     proof_hash: &BytesN<32>,
+    allowlist_proof: &Vec<BytesN<32>>,
 ) -> Result<(), Error> {
     // Verify quest exists and get its data
     let quest = storage::get_quest(env, quest_id)?;
@@ -26,15 +46,69 @@ pub fn submit_proof(
     // Validate quest has not expired
     validation::validate_quest_not_expired(env, quest.deadline)?;
 
+    allowlist::require_listed(env, quest_id, submitter, allowlist_proof)?;
+    if storage::get_team_lead(env, quest_id, submitter).is_some() {
+        return Err(Error::NotEligible);
+    }
+    chain::require_prerequisites(env, &quest, submitter)?;
+    reputation::require_reputation(env, &quest, submitter)?;
+    eligibility::require_holdings(env, quest_id, submitter)?;
+
+    let staged = !storage::get_milestones(env, quest_id).is_empty();
+    let mut attempt = 1;
+    if staged {
+        milestone::submit_proof(env, quest_id, submitter, milestone, proof_hash)?;
+    } else if milestone != 0 {
+        return Err(Error::MilestoneNotFound);
+    } else if let Ok(existing) = storage::get_submission(env, quest_id, submitter) {
+        attempt = existing.attempt;
+        match (existing.status.clone(), storage::get_repeat_policy(env, quest_id)) {
+            // Pending proofs can be replaced; approved ones (and their team) are final
+            (SubmissionStatus::Pending, _) => {}
+            (SubmissionStatus::Paid, Some(policy)) => {
+                if existing.attempt >= policy.max_completions {
+                    return Err(Error::AlreadyClaimed);
+                }
+                // The cooldown is a per-user timelock on the next attempt
+                if env.ledger().timestamp() < existing.timestamp.saturating_add(policy.cooldown_seconds) {
+                    return Err(Error::TimelockNotExpired);
+                }
+                // Archive the completed attempt; the team re-registers per attempt
+                storage::set_submission_attempt(env, &existing);
+                storage::remove_team(env, quest_id, submitter);
+                attempt += 1;
+            }
+            _ => return Err(Error::InvalidStatusTransition),
+        }
+    }
+
+    // Count first-time submitters, and index bounty entrants so the winner
+    // selection can iterate them
+    let first_proof = !storage::has_submission(env, quest_id, submitter);
+    if first_proof {
+        if quest.kind == QuestKind::Bounty {
+            if storage::get_submitter_count(env, quest_id) >= validation::MAX_BOUNTY_SUBMISSIONS {
+                return Err(Error::ArrayTooLong);
+            }
+            storage::add_quest_submitter(env, quest_id, submitter);
+        }
+        storage::increment_submitter_count(env, quest_id);
+    }
+
     let submission = Submission {
         quest_id: quest_id.clone(),
         submitter: submitter.clone(),
         proof_hash: proof_hash.clone(),
         status: SubmissionStatus::Pending,
         timestamp: env.ledger().timestamp(),
+        tier: 0,
+        attempt,
     };
 
-    storage::set_submission(env, quest_id, submitter, &submission);
+    // Staged quests keep one quest-level submission tracking the whole claim
+    if first_proof || !staged {
+        storage::set_submission(env, quest_id, submitter, &submission);
+    }
 
     // EMIT EVENT: ProofSubmitted
     events::proof_submitted(env, quest_id.clone(), submitter.clone(), proof_hash.clone());
@@ -46,8 +120,13 @@ pub fn submit_proof(
 ///
 /// Validates:
 /// - Quest exists and caller is the verifier
+/// - Quest has not been cancelled
+/// - Quest is not a bounty, whose winner is picked with `select_winner`
+/// - Quest is not staged, whose milestones are approved one by one
 /// - Submission exists
 /// - Submission status transition (Pending -> Approved) is valid
+/// - Paid plus pending claims stay within the quest's claim limit
+/// - Tiered quests have a tier left for this approval
 pub fn approve_submission(
     env: &Env,
     quest_id: &Symbol,
@@ -60,6 +139,19 @@ pub fn approve_submission(
         return Err(Error::Unauthorized);
     }
 
+    // No new obligations once the escrow has been refunded
+    if quest.status == QuestStatus::Cancelled {
+        return Err(Error::QuestNotActive);
+    }
+
+    if quest.kind == QuestKind::Bounty {
+        return Err(Error::WrongQuestKind);
+    }
+
+    if !storage::get_milestones(env, quest_id).is_empty() {
+        return Err(Error::MilestoneNotFound);
+    }
+
     let submission = storage::get_submission(env, quest_id, submitter)?;
 
     // Validate status transition: Pending -> Approved
@@ -68,7 +160,23 @@ pub fn approve_submission(
         &SubmissionStatus::Approved,
     )?;
 
+    if quest.total_claims + quest.pending_claims >= quest.max_claims {
+        return Err(Error::QuestFull);
+    }
+
+    // Early-bird tiers go by approval order: paid plus pending approvals so far
+    let tiers = storage::get_reward_tiers(env, quest_id);
+    if !tiers.is_empty() {
+        let (tier, amount) = quest::tier_for_approval(&tiers, quest.total_claims + quest.pending_claims)
+            .ok_or(Error::QuestFull)?;
+        let mut submission = submission;
+        submission.tier = tier;
+        storage::set_submission(env, quest_id, submitter, &submission);
+        storage::adjust_reserved_rewards(env, quest_id, amount);
+    }
+
     storage::update_submission_status(env, quest_id, submitter, SubmissionStatus::Approved)?;
+    storage::increment_quest_pending_claims(env, quest_id)?;
 
     // EMIT EVENT: SubmissionApproved
     events::submission_approved(env, quest_id.clone(), submitter.clone(), verifier.clone());
@@ -76,12 +184,146 @@ pub fn approve_submission(
     Ok(())
 }
 
+/// Pick the single winning submission of a bounty.
+///
+/// Approves the winner, rejects every other Pending submission and
+/// completes the quest so no further proof can be submitted.
+///
+/// Validates:
+/// - Quest exists and caller is the verifier
+/// - Quest is a bounty
+/// - Quest status transition (Active -> Completed) is valid
+/// - Winner's submission status transition (Pending -> Approved) is valid
+pub fn select_winner(
+    env: &Env,
+    quest_id: &Symbol,
+    winner: &Address,
+    verifier: &Address,
+) -> Result<u32, Error> {
+    let quest = storage::get_quest(env, quest_id)?;
+
+    if *verifier != quest.verifier {
+        return Err(Error::Unauthorized);
+    }
+
+    if quest.kind != QuestKind::Bounty {
+        return Err(Error::WrongQuestKind);
+    }
+
+    validation::validate_quest_status_transition(&quest.status, &QuestStatus::Completed)?;
+
+    let submission = storage::get_submission(env, quest_id, winner)?;
+    validation::validate_submission_status_transition(
+        &submission.status,
+        &SubmissionStatus::Approved,
+    )?;
+
+    storage::update_submission_status(env, quest_id, winner, SubmissionStatus::Approved)?;
+    storage::increment_quest_pending_claims(env, quest_id)?;
+    events::submission_approved(env, quest_id.clone(), winner.clone(), verifier.clone());
+
+    let mut rejected = 0u32;
+    for submitter in storage::get_quest_submitters(env, quest_id).iter() {
+        if submitter == *winner {
+            continue;
+        }
+        let entry = storage::get_submission(env, quest_id, &submitter)?;
+        if entry.status == SubmissionStatus::Pending {
+            storage::update_submission_status(env, quest_id, &submitter, SubmissionStatus::Rejected)?;
+            events::submission_rejected(env, quest_id.clone(), submitter, verifier.clone());
+            rejected += 1;
+        }
+    }
+
+    storage::update_quest_status(env, quest_id, QuestStatus::Completed)?;
+
+    // EMIT EVENT: WinnerSelected
+    events::winner_selected(env, quest_id.clone(), winner.clone(), rejected);
+
+    Ok(rejected)
+}
+
+/// Rank the approved entries of a contest and close it.
+///
+/// `ranked[i]` wins the prize of rank `i + 1`. Escrow beyond the assigned
+/// prizes, for the primary reward and every bundled leg, is refunded right
+/// away; the primary refund is split pro-rata between sponsors and is the
+/// returned amount.
+///
+/// Validates:
+/// - Quest exists and caller is the verifier
+/// - Quest is a contest
+/// - Quest status transition (Active -> Completed) is valid
+/// - No more winners than prizes, each listed once with an Approved submission
+pub fn finalize_ranking(
+    env: &Env,
+    quest_id: &Symbol,
+    verifier: &Address,
+    ranked: Vec<Address>,
+) -> Result<i128, Error> {
+    let mut quest = storage::get_quest(env, quest_id)?;
+
+    if *verifier != quest.verifier {
+        return Err(Error::Unauthorized);
+    }
+
+    if quest.kind != QuestKind::Contest {
+        return Err(Error::WrongQuestKind);
+    }
+
+    validation::validate_quest_status_transition(&quest.status, &QuestStatus::Completed)?;
+
+    let prizes = storage::get_prize_table(env, quest_id);
+    if ranked.len() > prizes.len() {
+        return Err(Error::ArrayTooLong);
+    }
+
+    let mut seen: Vec<Address> = Vec::new(env);
+    for (i, winner) in ranked.iter().enumerate() {
+        if seen.contains(&winner) {
+            return Err(Error::DuplicateWinner);
+        }
+        let submission = storage::get_submission(env, quest_id, &winner)?;
+        if submission.status != SubmissionStatus::Approved {
+            return Err(Error::InvalidSubmissionStatus);
+        }
+        storage::set_contest_rank(env, quest_id, &winner, i as u32 + 1);
+        seen.push_back(winner);
+    }
+
+    // Only ranked entries can claim from here on
+    quest.status = QuestStatus::Completed;
+    quest.pending_claims = ranked.len();
+    storage::set_quest(env, quest_id, &quest);
+
+    let winners = ranked.len() as i128;
+    let assigned: i128 = prizes.iter().take(ranked.len() as usize).sum();
+    let escrow = storage::get_quest_escrow(env, quest_id, &quest.reward_asset);
+    let refund = (escrow - assigned).max(0);
+    if refund > 0 {
+        quest::refund_primary(env, &quest, refund)?;
+    }
+
+    for leg in quest.extra_rewards.iter() {
+        let leg_refund = (storage::get_quest_escrow(env, quest_id, &leg.asset) - leg.amount * winners).max(0);
+        if leg_refund > 0 {
+            payout::refund_escrow(env, quest_id, &leg.asset, &quest.creator, leg_refund)?;
+        }
+    }
+
+    // EMIT EVENT: RankingFinalized
+    events::ranking_finalized(env, quest_id.clone(), verifier.clone(), ranked, refund);
+
+    Ok(refund)
+}
+
 /// Validate and process a reward claim for a submission.
 ///
 /// Validates:
+/// - Quest has not been swept as Expired (unclaimed rewards are void)
 /// - Submission is not already paid (AlreadyClaimed)
 /// - Submission status transition (Approved -> Paid) is valid
-/// - Quest claims have not exceeded the limit
+/// - Quest claims have not exceeded the global or per-quest limit
 pub fn validate_claim(
     env: &Env,
     quest_id: &Symbol,
@@ -90,6 +332,11 @@ pub fn validate_claim(
     let quest = storage::get_quest(env, quest_id)?;
     let submission = storage::get_submission(env, quest_id, submitter)?;
 
+    // Approved rewards are void once the quest has been swept
+    if quest.status == QuestStatus::Expired {
+        return Err(Error::QuestExpired);
+    }
+
     // Check if already claimed
     if submission.status == SubmissionStatus::Paid {
         return Err(Error::AlreadyClaimed);
@@ -103,6 +350,9 @@ pub fn validate_claim(
 
     // Validate quest claims limit
     validation::validate_quest_claims_limit(quest.total_claims)?;
+    if quest.total_claims >= quest.max_claims {
+        return Err(Error::QuestFull);
+    }
 
     Ok(())
 }
@@ -0,0 +1,95 @@
+use crate::chain;
+use crate::eligibility;
+use crate::errors::Error;
+use crate::reputation;
+use crate::storage;
+use crate::types::{SubmissionStatus, TeamMember};
+use crate::validation;
+use soroban_sdk::{vec, Address, Env, Symbol, Vec};
+
+/// Attach a team to a lead's pending submission.
+///
+/// Validates:
+/// - The lead's submission exists and has not been approved yet
+/// - Members are valid (see `validation::validate_team`)
+/// - The quest is not invite-only, since the allowlist proof only covers
+///   the lead (NotEligible)
+/// - Every member meets the quest's prerequisites, reputation gate and
+///   token holdings, like the lead did when submitting
+/// - No member has a submission of their own on the quest or joined another
+///   lead's team there (NotEligible), so nobody is paid twice for a claim
+///
+/// Replacing a pending team releases the members it drops. Members stay
+/// bound to the lead once the team has been approved, across attempts of
+/// a repeatable quest too.
+pub fn register_team(
+    env: &Env,
+    quest_id: &Symbol,
+    lead: &Address,
+    members: &Vec<TeamMember>,
+) -> Result<(), Error> {
+    let submission = storage::get_submission(env, quest_id, lead)?;
+    if submission.status != SubmissionStatus::Pending {
+        return Err(Error::InvalidStatusTransition);
+    }
+
+    validation::validate_team(lead, members)?;
+
+    if storage::get_allowlist_root(env, quest_id).is_some() {
+        return Err(Error::NotEligible);
+    }
+
+    for entry in storage::get_team(env, quest_id, lead).iter() {
+        storage::remove_team_lead(env, quest_id, &entry.member);
+    }
+
+    let quest = storage::get_quest(env, quest_id)?;
+    for entry in members.iter().filter(|entry| entry.member != *lead) {
+        let joined_other = storage::get_team_lead(env, quest_id, &entry.member)
+            .is_some_and(|other| other != *lead);
+        if joined_other || storage::has_submission(env, quest_id, &entry.member) {
+            return Err(Error::NotEligible);
+        }
+        chain::require_prerequisites(env, &quest, &entry.member)?;
+        reputation::require_reputation(env, &quest, &entry.member)?;
+        eligibility::require_holdings(env, quest_id, &entry.member)?;
+        storage::set_team_lead(env, quest_id, &entry.member, lead);
+    }
+    storage::set_team(env, quest_id, lead, members);
+
+    Ok(())
+}
+
+/// Everyone paid by a submitter's claim: the registered team, or the
+/// submitter alone with the full share.
+pub fn recipients(env: &Env, quest_id: &Symbol, submitter: &Address) -> Vec<TeamMember> {
+    let team = storage::get_team(env, quest_id, submitter);
+    if !team.is_empty() {
+        return team;
+    }
+    vec![
+        env,
+        TeamMember {
+            member: submitter.clone(),
+            share_bps: 10_000,
+        },
+    ]
+}
+
+/// Part of `amount` owed to member `index`, rounded down; the lead takes
+/// the rounding dust so the shares add up to `amount`.
+///
+/// Small shares of small amounts can round to 0, and callers skip those
+/// payouts; the lead's part is never 0 for a positive `amount`.
+pub fn share_of(amount: i128, members: &Vec<TeamMember>, lead: &Address, index: u32) -> i128 {
+    let share = |m: TeamMember| amount * m.share_bps as i128 / 10_000;
+    let entry = match members.get(index) {
+        Some(entry) => entry,
+        None => return 0,
+    };
+    if entry.member != *lead {
+        return share(entry);
+    }
+    let others: i128 = members.iter().filter(|m| m.member != *lead).map(share).sum();
+    amount - others
+}
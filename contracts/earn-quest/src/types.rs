@@ -11,6 +11,104 @@ pub struct Quest {
     pub deadline: u64,
     pub status: QuestStatus,
     pub total_claims: u32,
+    pub max_claims: u32,
+    pub pending_claims: u32,
+    pub vesting_locked: i128,
+    pub extra_rewards: Vec<RewardLeg>,
+    pub funded: bool,
+    pub pool: Option<Symbol>,
+    pub strategy: RewardStrategy,
+    pub mint_cap: i128,
+    pub minted: i128,
+    pub kind: QuestKind,
+    /// Quests a hunter must have been paid for before submitting proof
+    pub prerequisites: Vec<Symbol>,
+    /// Reputation level a hunter needs to submit proof; 0 for no minimum
+    pub min_level: u32,
+    /// Badges a hunter must hold to submit proof
+    pub required_badges: Vec<Badge>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum QuestKind {
+    /// Every approved submission is paid, up to `max_claims`
+    Standard,
+    /// The verifier picks a single winner; all other submissions are rejected
+    Bounty,
+    /// The verifier ranks approved submissions; each rank wins its prize
+    Contest,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RewardStrategy {
+    /// Pay rewards out of tokens escrowed in the contract
+    Escrow,
+    /// Mint rewards on claim; the contract must be the token's admin
+    Mint,
+}
+
+/// A team member's share of a submission's reward, in basis points.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TeamMember {
+    pub member: Address,
+    pub share_bps: u32,
+}
+
+/// A stage of a milestone quest paying `share_bps` of the per-claim reward.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    pub share_bps: u32,
+}
+
+/// Hunters must hold at least `min_balance` of `token` to submit proof.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HoldingRule {
+    pub token: Address,
+    pub min_balance: i128,
+}
+
+/// A quest in a dependency graph, with the quests it directly requires.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QuestNode {
+    pub id: Symbol,
+    pub prerequisites: Vec<Symbol>,
+}
+
+/// Lets each user complete a quest up to `max_completions` times, waiting
+/// `cooldown_seconds` after the previous proof before submitting again.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RepeatPolicy {
+    pub cooldown_seconds: u64,
+    pub max_completions: u32,
+}
+
+/// `count` consecutive approvals, in approval order, earning `amount` each.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardTier {
+    pub count: u32,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sponsorship {
+    pub sponsor: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardLeg {
+    pub asset: Address,
+    pub amount: i128,
 }
 
 #[contracttype]
@@ -21,6 +119,10 @@ pub struct Submission {
     pub proof_hash: BytesN<32>,
     pub status: SubmissionStatus,
     pub timestamp: u64,
+    /// Early-bird tier assigned at approval; 0 on quests without tiers
+    pub tier: u32,
+    /// 1-based attempt number; only repeatable quests go past 1
+    pub attempt: u32,
 }
 
 #[contracttype]
@@ -30,6 +132,7 @@ pub enum QuestStatus {
     Paused,
     Completed,
     Expired,
+    Cancelled,
 }
 
 #[contracttype]
@@ -59,3 +162,72 @@ pub enum Badge {
     Master,
     Legend,
 }
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetConfig {
+    pub min_reward: i128,
+    pub max_reward: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reconciliation {
+    pub balance: i128,
+    pub liabilities: i128,
+    pub surplus: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingSchedule {
+    pub cliff_seconds: u64,
+    pub duration_seconds: u64,
+    pub tranches: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingPosition {
+    pub quest_id: Symbol,
+    pub beneficiary: Address,
+    pub reward_asset: Address,
+    pub total_amount: i128,
+    pub withdrawn: i128,
+    pub start: u64,
+    pub schedule: VestingSchedule,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardPool {
+    pub id: Symbol,
+    pub owner: Address,
+    pub asset: Address,
+    pub balance: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolAllowance {
+    pub cap: i128,
+    pub spent: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutLimit {
+    pub max_amount: i128,
+    pub window_seconds: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawOverride {
+    pub asset: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub quests: Vec<Symbol>,
+    pub expires_at: u64,
+    pub approvals: Vec<Address>,
+}
@@ -1,6 +1,6 @@
 use crate::errors::Error;
-use crate::types::{QuestStatus, SubmissionStatus};
-use soroban_sdk::Env;
+use crate::types::{AssetConfig, Milestone, QuestStatus, SubmissionStatus, TeamMember, VestingSchedule};
+use soroban_sdk::{Address, Env, Vec};
 
 //================================================================================
 // Constants — Validation Limits
@@ -21,6 +21,54 @@ pub const MAX_BADGES_COUNT: u32 = 50;
 /// Maximum number of claims per quest
 pub const MAX_QUEST_CLAIMS: u32 = 10_000;
 
+/// Default time after a quest deadline during which approved rewards can still be claimed
+pub const DEFAULT_CLAIM_WINDOW_SECONDS: u64 = 7 * 24 * 60 * 60; // 7 days
+
+/// Time admins have to gather approvals for an emergency withdrawal override
+pub const OVERRIDE_APPROVAL_SECONDS: u64 = 24 * 60 * 60; // 1 day
+
+/// Maximum number of extra reward legs bundled with the primary reward
+pub const MAX_REWARD_LEGS: u32 = 4;
+
+/// Maximum number of quests whose escrow one withdrawal override writes off
+pub const MAX_OVERRIDE_QUESTS: u32 = 10;
+
+/// Number of buckets an asset's payout window is tracked in
+pub const MAX_PAYOUT_BUCKETS: u32 = 24;
+
+/// Maximum number of distinct sponsors contributing to one quest
+pub const MAX_SPONSORS: u32 = 50;
+
+/// Maximum number of submissions a bounty accepts before its winner is picked
+pub const MAX_BOUNTY_SUBMISSIONS: u32 = 100;
+
+/// Maximum number of early-bird reward tiers on a quest
+pub const MAX_REWARD_TIERS: u32 = 5;
+
+/// Maximum number of members sharing a team submission, lead included
+pub const MAX_TEAM_SIZE: u32 = 5;
+
+/// Maximum number of milestones on a staged quest
+pub const MAX_MILESTONES: u32 = 10;
+
+/// Maximum number of quests a quest directly requires
+pub const MAX_PREREQUISITES: u32 = 5;
+
+/// Maximum number of quests in a dependency chain, the quest itself included
+pub const MAX_QUEST_CHAIN: u32 = 20;
+
+/// Maximum number of token-holding rules on a quest
+pub const MAX_HOLDING_RULES: u32 = 3;
+
+/// Maximum depth of an allowlist Merkle proof, enough for 2^32 addresses
+pub const MAX_MERKLE_DEPTH: u32 = 32;
+
+/// Maximum number of ranked prizes in a contest
+pub const MAX_PRIZE_RANKS: u32 = 10;
+
+/// Maximum platform fee in basis points
+pub const MAX_FEE_BPS: u32 = 1_000; // 10%
+
 //================================================================================
 // Address Validation
 //================================================================================
@@ -70,6 +118,41 @@ pub fn validate_reward_amount(amount: i128) -> Result<(), Error> {
     Ok(())
 }
 
+/// Validates a per-claim reward against the bounds of its asset.
+///
+/// # Arguments
+/// * `amount` - The reward amount to validate
+/// * `config` - The allowlist entry of the reward asset
+///
+/// # Returns
+/// * `Ok(())` if amount is within [config.min_reward, config.max_reward]
+/// * `Err(Error::InvalidRewardAmount)` if amount <= 0 or below the asset minimum
+/// * `Err(Error::AmountTooLarge)` if amount exceeds the asset maximum
+pub fn validate_reward_for_asset(amount: i128, config: &AssetConfig) -> Result<(), Error> {
+    if amount <= 0 || amount < config.min_reward {
+        return Err(Error::InvalidRewardAmount);
+    }
+    if amount > config.max_reward {
+        return Err(Error::AmountTooLarge);
+    }
+    Ok(())
+}
+
+/// Validates the reward bounds of an allowlisted asset.
+///
+/// # Arguments
+/// * `config` - The proposed allowlist entry
+///
+/// # Returns
+/// * `Ok(())` if MIN_REWARD_AMOUNT <= min_reward <= max_reward
+/// * `Err(Error::InvalidRewardAmount)` otherwise
+pub fn validate_asset_config(config: &AssetConfig) -> Result<(), Error> {
+    if config.min_reward < MIN_REWARD_AMOUNT || config.min_reward > config.max_reward {
+        return Err(Error::InvalidRewardAmount);
+    }
+    Ok(())
+}
+
 //================================================================================
 // Deadline Validation
 //================================================================================
@@ -91,6 +174,25 @@ pub fn validate_deadline(env: &Env, deadline: u64) -> Result<(), Error> {
     Ok(())
 }
 
+/// Validates that a new deadline extends the current one and is in the future.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `current` - The current quest deadline
+/// * `new_deadline` - The requested deadline
+///
+/// # Returns
+/// * `Ok(())` if new_deadline > current and new_deadline > current_timestamp
+/// * `Err(Error::DeadlineInPast)` if new_deadline <= current_timestamp
+/// * `Err(Error::DeadlineNotExtended)` if new_deadline <= current
+pub fn validate_deadline_extension(env: &Env, current: u64, new_deadline: u64) -> Result<(), Error> {
+    validate_deadline(env, new_deadline)?;
+    if new_deadline <= current {
+        return Err(Error::DeadlineNotExtended);
+    }
+    Ok(())
+}
+
 /// Validates that a quest has not expired (its deadline has not passed).
 ///
 /// # Arguments
@@ -108,6 +210,24 @@ pub fn validate_quest_not_expired(env: &Env, deadline: u64) -> Result<(), Error>
     Ok(())
 }
 
+/// Validates that the claim window following a quest deadline has closed.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `deadline` - The quest deadline timestamp
+/// * `claim_window` - Seconds after the deadline during which claims stay open
+///
+/// # Returns
+/// * `Ok(())` if current_time >= deadline + claim_window
+/// * `Err(Error::QuestStillActive)` if approved rewards can still be claimed
+pub fn validate_claim_window_closed(env: &Env, deadline: u64, claim_window: u64) -> Result<(), Error> {
+    let current_time = env.ledger().timestamp();
+    if current_time < deadline.saturating_add(claim_window) {
+        return Err(Error::QuestStillActive);
+    }
+    Ok(())
+}
+
 //================================================================================
 // String / Symbol Length Validation
 //================================================================================
@@ -190,8 +310,12 @@ pub fn validate_badge_count(current_count: u32) -> Result<(), Error> {
 /// * Active -> Paused
 /// * Active -> Completed
 /// * Active -> Expired
+/// * Active -> Cancelled
+/// * Completed -> Expired
+/// * Cancelled -> Expired
 /// * Paused -> Active
 /// * Paused -> Expired
+/// * Paused -> Cancelled
 ///
 /// # Arguments
 /// * `from` - Current quest status
@@ -204,14 +328,18 @@ pub fn validate_quest_status_transition(
     from: &QuestStatus,
     to: &QuestStatus,
 ) -> Result<(), Error> {
-    let valid = match (from, to) {
-        (QuestStatus::Active, QuestStatus::Paused) => true,
-        (QuestStatus::Active, QuestStatus::Completed) => true,
-        (QuestStatus::Active, QuestStatus::Expired) => true,
-        (QuestStatus::Paused, QuestStatus::Active) => true,
-        (QuestStatus::Paused, QuestStatus::Expired) => true,
-        _ => false,
-    };
+    let valid = matches!(
+        (from, to),
+        (QuestStatus::Active, QuestStatus::Paused)
+            | (QuestStatus::Active, QuestStatus::Completed)
+            | (QuestStatus::Active, QuestStatus::Expired)
+            | (QuestStatus::Active, QuestStatus::Cancelled)
+            | (QuestStatus::Completed, QuestStatus::Expired)
+            | (QuestStatus::Cancelled, QuestStatus::Expired)
+            | (QuestStatus::Paused, QuestStatus::Active)
+            | (QuestStatus::Paused, QuestStatus::Expired)
+            | (QuestStatus::Paused, QuestStatus::Cancelled)
+    );
 
     if !valid {
         return Err(Error::InvalidStatusTransition);
@@ -237,12 +365,12 @@ pub fn validate_submission_status_transition(
     from: &SubmissionStatus,
     to: &SubmissionStatus,
 ) -> Result<(), Error> {
-    let valid = match (from, to) {
-        (SubmissionStatus::Pending, SubmissionStatus::Approved) => true,
-        (SubmissionStatus::Pending, SubmissionStatus::Rejected) => true,
-        (SubmissionStatus::Approved, SubmissionStatus::Paid) => true,
-        _ => false,
-    };
+    let valid = matches!(
+        (from, to),
+        (SubmissionStatus::Pending, SubmissionStatus::Approved)
+            | (SubmissionStatus::Pending, SubmissionStatus::Rejected)
+            | (SubmissionStatus::Approved, SubmissionStatus::Paid)
+    );
 
     if !valid {
         return Err(Error::InvalidStatusTransition);
@@ -279,3 +407,126 @@ pub fn validate_quest_claims_limit(total_claims: u32) -> Result<(), Error> {
     }
     Ok(())
 }
+
+/// Validates the maximum number of claims configured for a quest.
+///
+/// # Arguments
+/// * `max_claims` - The participant cap requested for the quest
+///
+/// # Returns
+/// * `Ok(())` if 0 < max_claims <= MAX_QUEST_CLAIMS
+/// * `Err(Error::InvalidMaxClaims)` if max_claims is 0
+/// * `Err(Error::ArrayTooLong)` if max_claims > MAX_QUEST_CLAIMS
+pub fn validate_max_claims(max_claims: u32) -> Result<(), Error> {
+    if max_claims == 0 {
+        return Err(Error::InvalidMaxClaims);
+    }
+    if max_claims > MAX_QUEST_CLAIMS {
+        return Err(Error::ArrayTooLong);
+    }
+    Ok(())
+}
+
+/// Validates a vesting schedule.
+///
+/// # Arguments
+/// * `schedule` - The vesting schedule to validate
+///
+/// # Returns
+/// * `Ok(())` if tranches > 0, duration >= tranches and cliff <= duration
+/// * `Err(Error::InvalidVestingSchedule)` otherwise
+pub fn validate_vesting_schedule(schedule: &VestingSchedule) -> Result<(), Error> {
+    if schedule.tranches == 0
+        || schedule.duration_seconds < schedule.tranches as u64
+        || schedule.cliff_seconds > schedule.duration_seconds
+    {
+        return Err(Error::InvalidVestingSchedule);
+    }
+    Ok(())
+}
+
+/// Validates the milestones of a staged quest.
+///
+/// # Arguments
+/// * `milestones` - The milestones in delivery order
+///
+/// # Returns
+/// * `Ok(())` if there are 1..=MAX_MILESTONES milestones with non-zero
+///   shares adding up to 10_000 bps
+/// * `Err(Error::ArrayTooLong)` if there are too many milestones
+/// * `Err(Error::InvalidShares)` otherwise
+pub fn validate_milestones(milestones: &Vec<Milestone>) -> Result<(), Error> {
+    if milestones.len() > MAX_MILESTONES {
+        return Err(Error::ArrayTooLong);
+    }
+
+    let mut total: u32 = 0;
+    for milestone in milestones.iter() {
+        if milestone.share_bps == 0 {
+            return Err(Error::InvalidShares);
+        }
+        total = total.saturating_add(milestone.share_bps);
+    }
+
+    if milestones.is_empty() || total != 10_000 {
+        return Err(Error::InvalidShares);
+    }
+    Ok(())
+}
+
+/// Validates the members of a team submission.
+///
+/// # Arguments
+/// * `lead` - The address submitting on behalf of the team
+/// * `members` - Every member's share, lead included
+///
+/// # Returns
+/// * `Ok(())` if there are 2..=MAX_TEAM_SIZE distinct members including the
+///   lead, with non-zero shares adding up to 10_000 bps
+/// * `Err(Error::ArrayTooLong)` if the team is too large
+/// * `Err(Error::InvalidShares)` otherwise
+pub fn validate_team(lead: &Address, members: &Vec<TeamMember>) -> Result<(), Error> {
+    if members.len() > MAX_TEAM_SIZE {
+        return Err(Error::ArrayTooLong);
+    }
+    if members.len() < 2 {
+        return Err(Error::InvalidShares);
+    }
+
+    let mut total: u32 = 0;
+    let mut has_lead = false;
+    for (i, entry) in members.iter().enumerate() {
+        if entry.share_bps == 0 {
+            return Err(Error::InvalidShares);
+        }
+        let repeated = members
+            .iter()
+            .skip(i + 1)
+            .any(|other| other.member == entry.member);
+        if repeated {
+            return Err(Error::InvalidShares);
+        }
+        has_lead |= entry.member == *lead;
+        total = total.saturating_add(entry.share_bps);
+    }
+
+    if !has_lead || total != 10_000 {
+        return Err(Error::InvalidShares);
+    }
+    Ok(())
+}
+
+/// Validates a platform fee.
+///
+/// # Arguments
+/// * `fee_bps` - The fee in basis points
+///
+/// # Returns
+/// * `Ok(())` if fee_bps <= MAX_FEE_BPS
+/// * `Err(Error::FeeTooHigh)` otherwise
+pub fn validate_fee_bps(fee_bps: u32) -> Result<(), Error> {
+    if fee_bps > MAX_FEE_BPS {
+        return Err(Error::FeeTooHigh);
+    }
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use crate::errors::Error;
+use crate::events;
+use crate::payout;
+use crate::storage;
+use crate::types::{Quest, VestingPosition, VestingSchedule};
+use soroban_sdk::{Address, Env, Symbol};
+
+/// Lock a claimed reward into a vesting position instead of paying it out.
+///
+/// Returns the platform fee paid to the treasury at claim time.
+///
+/// The tokens stay in the quest's escrow until withdrawn, so the position
+/// is backed by funds nobody else can claim or refund.
+pub fn start_vesting(
+    env: &Env,
+    quest: &Quest,
+    beneficiary: &Address,
+    schedule: VestingSchedule,
+) -> Result<i128, Error> {
+    let available = storage::get_quest_escrow(env, &quest.id, &quest.reward_asset) - quest.vesting_locked;
+    if available < quest.reward_amount {
+        return Err(Error::InsufficientBalance);
+    }
+
+    // The platform fee is taken up front; only the net reward vests
+    let fee = payout::pay_platform_fee(env, &quest.id, &quest.reward_asset, quest.reward_amount)?;
+    let amount = quest.reward_amount - fee;
+
+    let position = VestingPosition {
+        quest_id: quest.id.clone(),
+        beneficiary: beneficiary.clone(),
+        reward_asset: quest.reward_asset.clone(),
+        total_amount: amount,
+        withdrawn: 0,
+        start: env.ledger().timestamp(),
+        schedule,
+    };
+
+    storage::set_vesting_position(env, &position);
+    storage::adjust_quest_vesting_locked(env, &quest.id, amount)?;
+
+    Ok(fee)
+}
+
+/// Amount of a position unlocked so far (including already withdrawn tokens).
+///
+/// Nothing unlocks before the cliff; afterwards the reward unlocks in equal
+/// tranches over the duration, and fully once the duration has elapsed.
+/// Tranche boundaries are spread over the whole duration, so a duration that
+/// does not divide evenly by the tranche count never unlocks early.
+pub fn vested_amount(env: &Env, position: &VestingPosition) -> i128 {
+    let schedule = &position.schedule;
+    let elapsed = env.ledger().timestamp().saturating_sub(position.start);
+
+    if elapsed < schedule.cliff_seconds {
+        return 0;
+    }
+    if elapsed >= schedule.duration_seconds {
+        return position.total_amount;
+    }
+
+    // Before the end of the duration this stays below the tranche count
+    let unlocked_tranches =
+        elapsed as u128 * schedule.tranches as u128 / schedule.duration_seconds as u128;
+    let unlocked_tranches = unlocked_tranches.min(schedule.tranches as u128);
+
+    position.total_amount * unlocked_tranches as i128 / schedule.tranches as i128
+}
+
+/// Withdraw everything vested but not yet withdrawn from a position.
+pub fn withdraw_vested(env: &Env, quest_id: &Symbol, beneficiary: &Address) -> Result<i128, Error> {
+    let mut position = storage::get_vesting_position(env, quest_id, beneficiary)?;
+
+    let amount = vested_amount(env, &position) - position.withdrawn;
+    if amount <= 0 {
+        return Err(Error::NothingToWithdraw);
+    }
+
+    payout::release_vested(env, quest_id, &position.reward_asset, beneficiary, amount)?;
+
+    position.withdrawn += amount;
+    storage::set_vesting_position(env, &position);
+    storage::adjust_quest_vesting_locked(env, quest_id, -amount)?;
+
+    // EMIT EVENT: VestedWithdrawn
+    events::vested_withdrawn(
+        env,
+        quest_id.clone(),
+        beneficiary.clone(),
+        position.reward_asset,
+        amount,
+        position.withdrawn,
+    );
+
+    Ok(amount)
+}
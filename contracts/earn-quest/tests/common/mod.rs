@@ -0,0 +1,60 @@
+// Shared by every integration test binary; each one uses only part of it
+#![allow(dead_code)]
+
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{testutils::Address as _, Address, Env, Symbol};
+
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+/// Allowlist a reward asset with the widest bounds so quests can be registered.
+pub fn allow_asset(env: &Env, client: &EarnQuestContractClient, asset: &Address) {
+    let asset_admin = Address::generate(env);
+    client.initialize(&asset_admin);
+    client.allow_asset(&asset_admin, asset, &1, &earn_quest::validation::MAX_REWARD_AMOUNT);
+}
+
+pub struct FundedQuest<'a> {
+    pub contract_id: Address,
+    pub client: EarnQuestContractClient<'a>,
+    pub token: Address,
+    pub token_admin: StellarAssetClient<'a>,
+    pub token_client: TokenClient<'a>,
+    pub creator: Address,
+    pub verifier: Address,
+}
+
+/// Register a contract and token, and a quest paying `reward` per claim for
+/// up to `max_claims` claims, fully funded by its creator.
+pub fn setup_funded_quest<'a>(
+    env: &'a Env,
+    quest_id: &Symbol,
+    reward: i128,
+    max_claims: u32,
+) -> FundedQuest<'a> {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(admin).address();
+    let token_admin = StellarAssetClient::new(env, &token);
+    let token_client = TokenClient::new(env, &token);
+
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+
+    allow_asset(env, &client, &token);
+
+    client.register_quest(quest_id, &creator, &token, &reward, &verifier, &100000, &max_claims, &None);
+    token_admin.mint(&creator, &(reward * max_claims as i128));
+    client.fund_quest(quest_id, &creator);
+
+    FundedQuest {
+        contract_id,
+        client,
+        token,
+        token_admin,
+        token_client,
+        creator,
+        verifier,
+    }
+}
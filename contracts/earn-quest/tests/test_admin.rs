@@ -1,9 +1,10 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env};
 
 extern crate earn_quest;
-use earn_quest::types::Badge;
+use earn_quest::errors::Error;
+use earn_quest::types::{AssetConfig, Badge};
 use earn_quest::{EarnQuestContract, EarnQuestContractClient};
 
 //================================================================================
@@ -268,3 +269,85 @@ fn test_remove_non_existent_admin() {
 
     assert!(!client.is_admin(&non_admin));
 }
+
+//================================================================================
+// Asset Allowlist Tests
+//================================================================================
+
+#[test]
+fn test_admin_can_allow_and_remove_asset() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let xlm = Address::generate(&env);
+
+    client.initialize(&admin);
+    client.allow_asset(&admin, &usdc, &10, &1000);
+    client.allow_asset(&admin, &xlm, &1, &500);
+
+    assert_eq!(client.get_allowed_assets(), vec![&env, usdc.clone(), xlm.clone()]);
+    assert_eq!(
+        client.get_asset_config(&usdc),
+        AssetConfig {
+            min_reward: 10,
+            max_reward: 1000
+        }
+    );
+
+    // Updating bounds does not duplicate the entry
+    client.allow_asset(&admin, &usdc, &20, &2000);
+    assert_eq!(client.get_allowed_assets().len(), 2);
+    assert_eq!(client.get_asset_config(&usdc).min_reward, 20);
+
+    client.remove_asset(&admin, &usdc);
+    assert_eq!(client.get_allowed_assets(), vec![&env, xlm]);
+    assert_eq!(client.try_get_asset_config(&usdc), Err(Ok(Error::InvalidAsset)));
+}
+
+#[test]
+fn test_non_admin_cannot_allow_asset() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    let result = client.try_allow_asset(&Address::generate(&env), &asset, &1, &100);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    let result = client.try_allow_asset(&admin, &asset, &100, &1);
+    assert_eq!(result, Err(Ok(Error::InvalidRewardAmount)));
+}
+
+#[test]
+fn test_register_quest_requires_allowed_asset_and_bounds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    let result = client.try_register_quest(&symbol_short!("AQ1"), &creator, &asset, &50, &verifier, &10000, &1, &None);
+    assert_eq!(result, Err(Ok(Error::InvalidAsset)));
+
+    client.allow_asset(&admin, &asset, &10, &100);
+
+    let result = client.try_register_quest(&symbol_short!("AQ1"), &creator, &asset, &5, &verifier, &10000, &1, &None);
+    assert_eq!(result, Err(Ok(Error::InvalidRewardAmount)));
+
+    let result = client.try_register_quest(&symbol_short!("AQ1"), &creator, &asset, &101, &verifier, &10000, &1, &None);
+    assert_eq!(result, Err(Ok(Error::AmountTooLarge)));
+
+    client.register_quest(&symbol_short!("AQ1"), &creator, &asset, &100, &verifier, &10000, &1, &None);
+}
@@ -0,0 +1,137 @@
+#![cfg(test)]
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, Bytes, BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+extern crate earn_quest;
+use earn_quest::errors::Error;
+
+mod common;
+use common::setup_funded_quest;
+
+//================================================================================
+// Test Setup
+//================================================================================
+
+fn leaf(env: &Env, address: &Address) -> BytesN<32> {
+    env.crypto().sha256(&address.clone().to_xdr(env)).to_bytes()
+}
+
+fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut pair = Bytes::from(first.clone());
+    pair.append(&Bytes::from(second.clone()));
+    env.crypto().sha256(&pair).to_bytes()
+}
+
+/// Merkle root of four addresses and the proof of the first one.
+fn tree_of_four(env: &Env, members: &[Address; 4]) -> (BytesN<32>, Vec<BytesN<32>>) {
+    let leaves: std::vec::Vec<BytesN<32>> = members.iter().map(|m| leaf(env, m)).collect();
+    let left = hash_pair(env, &leaves[0], &leaves[1]);
+    let right = hash_pair(env, &leaves[2], &leaves[3]);
+    let root = hash_pair(env, &left, &right);
+    (root, vec![env, leaves[1].clone(), right])
+}
+
+//================================================================================
+// Allowlist Tests
+//================================================================================
+
+#[test]
+fn test_only_listed_addresses_submit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("AL1");
+    let f = setup_funded_quest(&env, &quest_id, 100, 5);
+    let members = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let (root, proof) = tree_of_four(&env, &members);
+    let proof_hash = BytesN::from_array(&env, &[1u8; 32]);
+
+    f.client.set_allowlist_root(&quest_id, &f.creator, &root);
+    assert_eq!(f.client.get_allowlist_root(&quest_id), Some(root));
+
+    // Outsiders cannot reuse a member's proof
+    let outsider = Address::generate(&env);
+    let res = f.client.try_submit_proof(&quest_id, &outsider, &0, &proof_hash, &proof);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+
+    let res = f.client.try_submit_proof(&quest_id, &members[0], &0, &proof_hash, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+
+    f.client.submit_proof(&quest_id, &members[0], &0, &proof_hash, &proof);
+}
+
+#[test]
+fn test_rotate_and_remove_allowlist_root() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("AL2");
+    let f = setup_funded_quest(&env, &quest_id, 100, 5);
+    let cohort = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let next_cohort = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let (root, proof) = tree_of_four(&env, &cohort);
+    let (next_root, next_proof) = tree_of_four(&env, &next_cohort);
+    let proof_hash = BytesN::from_array(&env, &[1u8; 32]);
+
+    f.client.set_allowlist_root(&quest_id, &f.creator, &root);
+    f.client.set_allowlist_root(&quest_id, &f.creator, &next_root);
+
+    let event = env.events().all().last().unwrap();
+    let name: Symbol = event.1.get(0).unwrap().into_val(&env);
+    assert_eq!(name, symbol_short!("al_root"));
+    let data: (Option<BytesN<32>>,) = event.2.into_val(&env);
+    assert_eq!(data, (Some(next_root),));
+
+    let res = f.client.try_submit_proof(&quest_id, &cohort[0], &0, &proof_hash, &proof);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+    f.client.submit_proof(&quest_id, &next_cohort[0], &0, &proof_hash, &next_proof);
+
+    // Without a root the quest is open to everyone
+    f.client.remove_allowlist_root(&quest_id, &f.creator);
+    assert_eq!(f.client.get_allowlist_root(&quest_id), None);
+    f.client.submit_proof(&quest_id, &Address::generate(&env), &0, &proof_hash, &Vec::new(&env));
+}
+
+#[test]
+fn test_allowlist_root_creator_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("AL3");
+    let f = setup_funded_quest(&env, &quest_id, 100, 5);
+    let root = BytesN::from_array(&env, &[7u8; 32]);
+
+    let res = f.client.try_set_allowlist_root(&quest_id, &Address::generate(&env), &root);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+
+    // Proofs deeper than any real tree are rejected up front
+    f.client.set_allowlist_root(&quest_id, &f.creator, &root);
+    let mut deep = Vec::new(&env);
+    for _ in 0..=earn_quest::validation::MAX_MERKLE_DEPTH {
+        deep.push_back(root.clone());
+    }
+    let proof_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let res = f.client.try_submit_proof(&quest_id, &Address::generate(&env), &0, &proof_hash, &deep);
+    assert_eq!(res, Err(Ok(Error::ArrayTooLong)));
+}
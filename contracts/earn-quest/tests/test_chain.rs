@@ -0,0 +1,210 @@
+#![cfg(test)]
+
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, BytesN, Env, Symbol, Vec};
+
+extern crate earn_quest;
+use earn_quest::errors::Error;
+use earn_quest::types::{QuestNode, TeamMember};
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+mod common;
+use common::allow_asset;
+
+//================================================================================
+// Test Setup
+//================================================================================
+
+struct ChainFixture<'a> {
+    env: &'a Env,
+    client: EarnQuestContractClient<'a>,
+    token_admin: StellarAssetClient<'a>,
+    token: Address,
+    creator: Address,
+    verifier: Address,
+}
+
+fn setup<'a>(env: &'a Env) -> ChainFixture<'a> {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(admin).address();
+    let token_admin = StellarAssetClient::new(env, &token);
+
+    allow_asset(env, &client, &token);
+
+    ChainFixture {
+        env,
+        client,
+        token_admin,
+        token,
+        creator: Address::generate(env),
+        verifier: Address::generate(env),
+    }
+}
+
+/// Register and fund a quest paying 100 per claim for up to 5 claims.
+fn add_quest(f: &ChainFixture, quest_id: &Symbol) {
+    f.client.register_quest(quest_id, &f.creator, &f.token, &100, &f.verifier, &100000, &5, &None);
+    f.token_admin.mint(&f.creator, &500);
+    f.client.fund_quest(quest_id, &f.creator);
+}
+
+fn proof(f: &ChainFixture) -> BytesN<32> {
+    BytesN::from_array(f.env, &[1u8; 32])
+}
+
+//================================================================================
+// Prerequisite Tests
+//================================================================================
+
+#[test]
+fn test_quest_unlocks_after_prerequisite_paid() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let f = setup(&env);
+    let (intro, advanced) = (symbol_short!("INTRO"), symbol_short!("ADV"));
+    add_quest(&f, &intro);
+    add_quest(&f, &advanced);
+    f.client.set_prerequisites(&advanced, &f.creator, &vec![&env, intro.clone()]);
+
+    let user = Address::generate(&env);
+    let res = f.client.try_submit_proof(&advanced, &user, &0, &proof(&f), &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::PrerequisiteNotMet)));
+
+    // Approval alone is not enough; the prerequisite must be paid
+    f.client.submit_proof(&intro, &user, &0, &proof(&f), &Vec::new(&env));
+    f.client.approve_submission(&intro, &user, &f.verifier);
+    let res = f.client.try_submit_proof(&advanced, &user, &0, &proof(&f), &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::PrerequisiteNotMet)));
+
+    f.client.claim_reward(&intro, &user);
+    f.client.submit_proof(&advanced, &user, &0, &proof(&f), &Vec::new(&env));
+}
+
+#[test]
+fn test_team_members_meet_prerequisite_paid_through_lead() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let f = setup(&env);
+    let (intro, advanced) = (symbol_short!("INTRO"), symbol_short!("ADV"));
+    add_quest(&f, &intro);
+    add_quest(&f, &advanced);
+    f.client.set_prerequisites(&advanced, &f.creator, &vec![&env, intro.clone()]);
+
+    let (lead, member) = (Address::generate(&env), Address::generate(&env));
+    let team = vec![
+        &env,
+        TeamMember { member: lead.clone(), share_bps: 5_000 },
+        TeamMember { member: member.clone(), share_bps: 5_000 },
+    ];
+    f.client.submit_team_proof(&intro, &lead, &team, &0, &proof(&f), &Vec::new(&env));
+    f.client.approve_submission(&intro, &lead, &f.verifier);
+    f.client.claim_reward(&intro, &lead);
+
+    f.client.submit_proof(&advanced, &member, &0, &proof(&f), &Vec::new(&env));
+    f.client.submit_proof(&advanced, &lead, &0, &proof(&f), &Vec::new(&env));
+}
+
+#[test]
+fn test_get_quest_chain() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let f = setup(&env);
+    let (a, b, c, d) = (
+        symbol_short!("A"),
+        symbol_short!("B"),
+        symbol_short!("C"),
+        symbol_short!("D"),
+    );
+    for id in [&a, &b, &c, &d] {
+        add_quest(&f, id);
+    }
+    f.client.set_prerequisites(&b, &f.creator, &vec![&env, a.clone()]);
+    f.client.set_prerequisites(&c, &f.creator, &vec![&env, a.clone()]);
+    f.client.set_prerequisites(&d, &f.creator, &vec![&env, b.clone(), c.clone()]);
+
+    // Shared prerequisites appear once
+    let chain = f.client.get_quest_chain(&d);
+    assert_eq!(
+        chain,
+        vec![
+            &env,
+            QuestNode { id: d.clone(), prerequisites: vec![&env, b.clone(), c.clone()] },
+            QuestNode { id: b.clone(), prerequisites: vec![&env, a.clone()] },
+            QuestNode { id: c.clone(), prerequisites: vec![&env, a.clone()] },
+            QuestNode { id: a.clone(), prerequisites: vec![&env] },
+        ]
+    );
+}
+
+#[test]
+fn test_get_quest_chain_stops_at_max_length() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let f = setup(&env);
+    let len = earn_quest::validation::MAX_QUEST_CHAIN;
+    let ids: std::vec::Vec<Symbol> = (0..=len).map(|i| Symbol::new(&env, &format!("Q{}", i))).collect();
+    for id in ids.iter() {
+        add_quest(&f, id);
+    }
+
+    // Linking from the top down checks each edited quest's short chain only
+    for i in (1..ids.len()).rev() {
+        f.client.set_prerequisites(&ids[i], &f.creator, &vec![&env, ids[i - 1].clone()]);
+    }
+
+    let top = &ids[len as usize];
+    let chain = f.client.get_quest_chain(top);
+    assert_eq!(chain.len(), len);
+    assert_eq!(chain.get_unchecked(0).id, top.clone());
+
+    // Setting a chain that long directly is still rejected
+    let extra = symbol_short!("EXTRA");
+    add_quest(&f, &extra);
+    let res = f.client.try_set_prerequisites(&extra, &f.creator, &vec![&env, top.clone()]);
+    assert_eq!(res, Err(Ok(Error::ArrayTooLong)));
+}
+
+#[test]
+fn test_invalid_prerequisites_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let f = setup(&env);
+    let (a, b) = (symbol_short!("A"), symbol_short!("B"));
+    add_quest(&f, &a);
+    add_quest(&f, &b);
+
+    let res = f.client.try_set_prerequisites(&a, &f.creator, &vec![&env, a.clone()]);
+    assert_eq!(res, Err(Ok(Error::PrerequisiteCycle)));
+
+    let res = f.client.try_set_prerequisites(&a, &f.creator, &vec![&env, symbol_short!("NOPE")]);
+    assert_eq!(res, Err(Ok(Error::QuestNotFound)));
+
+    // A cycle would lock both quests forever
+    f.client.set_prerequisites(&b, &f.creator, &vec![&env, a.clone()]);
+    let res = f.client.try_set_prerequisites(&a, &f.creator, &vec![&env, b.clone()]);
+    assert_eq!(res, Err(Ok(Error::PrerequisiteCycle)));
+}
+
+#[test]
+fn test_prerequisites_locked_after_submission() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let f = setup(&env);
+    let (a, b) = (symbol_short!("A"), symbol_short!("B"));
+    add_quest(&f, &a);
+    add_quest(&f, &b);
+
+    f.client.submit_proof(&b, &Address::generate(&env), &0, &proof(&f), &Vec::new(&env));
+    let res = f.client.try_set_prerequisites(&b, &f.creator, &vec![&env, a.clone()]);
+    assert_eq!(res, Err(Ok(Error::QuestTermsLocked)));
+}
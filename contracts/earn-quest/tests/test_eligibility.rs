@@ -0,0 +1,102 @@
+#![cfg(test)]
+
+use soroban_sdk::token::StellarAssetClient;
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, BytesN, Env, Vec};
+
+extern crate earn_quest;
+use earn_quest::errors::Error;
+use earn_quest::types::HoldingRule;
+
+mod common;
+use common::setup_funded_quest;
+
+//================================================================================
+// Test Setup
+//================================================================================
+
+/// A sponsor token, unrelated to the reward asset.
+fn sponsor_token(env: &Env) -> StellarAssetClient<'_> {
+    let token = env.register_stellar_asset_contract_v2(Address::generate(env)).address();
+    StellarAssetClient::new(env, &token)
+}
+
+//================================================================================
+// Token-Holding Gate Tests
+//================================================================================
+
+#[test]
+fn test_holders_of_minimum_balance_submit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("HOLD1");
+    let f = setup_funded_quest(&env, &quest_id, 100, 5);
+    let sponsor = sponsor_token(&env);
+    let rules = vec![&env, HoldingRule { token: sponsor.address.clone(), min_balance: 50 }];
+    f.client.set_holding_rules(&quest_id, &f.creator, &rules);
+    assert_eq!(f.client.get_holding_rules(&quest_id), rules);
+
+    let user = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+
+    sponsor.mint(&user, &49);
+    let res = f.client.try_submit_proof(&quest_id, &user, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::NotEligible)));
+
+    sponsor.mint(&user, &1);
+    f.client.submit_proof(&quest_id, &user, &0, &proof, &Vec::new(&env));
+}
+
+#[test]
+fn test_every_holding_rule_required() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("HOLD2");
+    let f = setup_funded_quest(&env, &quest_id, 100, 5);
+    let (first, second) = (sponsor_token(&env), sponsor_token(&env));
+    let rules = vec![
+        &env,
+        HoldingRule { token: first.address.clone(), min_balance: 10 },
+        HoldingRule { token: second.address.clone(), min_balance: 20 },
+    ];
+    f.client.set_holding_rules(&quest_id, &f.creator, &rules);
+
+    let user = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+
+    first.mint(&user, &1000);
+    let res = f.client.try_submit_proof(&quest_id, &user, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::NotEligible)));
+
+    second.mint(&user, &20);
+    f.client.submit_proof(&quest_id, &user, &0, &proof, &Vec::new(&env));
+}
+
+#[test]
+fn test_invalid_holding_rules_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("HOLD3");
+    let f = setup_funded_quest(&env, &quest_id, 100, 5);
+    let sponsor = sponsor_token(&env);
+
+    let zero = vec![&env, HoldingRule { token: sponsor.address.clone(), min_balance: 0 }];
+    let res = f.client.try_set_holding_rules(&quest_id, &f.creator, &zero);
+    assert_eq!(res, Err(Ok(Error::InvalidRewardAmount)));
+
+    let mut many = Vec::new(&env);
+    for _ in 0..=earn_quest::validation::MAX_HOLDING_RULES {
+        many.push_back(HoldingRule { token: sponsor.address.clone(), min_balance: 1 });
+    }
+    let res = f.client.try_set_holding_rules(&quest_id, &f.creator, &many);
+    assert_eq!(res, Err(Ok(Error::ArrayTooLong)));
+
+    // Rules cannot change once hunters have submitted
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    f.client.submit_proof(&quest_id, &Address::generate(&env), &0, &proof, &Vec::new(&env));
+    let rules = vec![&env, HoldingRule { token: sponsor.address.clone(), min_balance: 1 }];
+    let res = f.client.try_set_holding_rules(&quest_id, &f.creator, &rules);
+    assert_eq!(res, Err(Ok(Error::QuestTermsLocked)));
+}
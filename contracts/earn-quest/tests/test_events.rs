@@ -2,9 +2,12 @@
 
 extern crate earn_quest;
 use earn_quest::{EarnQuestContract, EarnQuestContractClient};
-use soroban_sdk::{Address, BytesN, Env, IntoVal, Symbol, symbol_short, testutils::{Address as _, Events}};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, Symbol, Vec, symbol_short, testutils::{Address as _, Events}};
 use soroban_sdk::token::StellarAssetClient;
 
+mod common;
+use common::allow_asset;
+
 #[test]
 fn test_full_quest_lifecycle_events() {
     let env = Env::default();
@@ -20,10 +23,6 @@ fn test_full_quest_lifecycle_events() {
     let token_address = token_contract_obj.address();
     let token_admin_client = StellarAssetClient::new(&env, &token_address);
     
-    // Fund the contract so it can pay out rewards later
-    let fund_amount = 1000i128;
-    token_admin_client.mint(&contract_id, &fund_amount);
-
     // 3. Test Data
     let creator = Address::generate(&env);
     let verifier = Address::generate(&env);
@@ -32,6 +31,7 @@ fn test_full_quest_lifecycle_events() {
     let reward_amount = 100i128;
     let deadline = 5000u64;
 
+    allow_asset(&env, &client, &token_address);
     // --- STEP 1: REGISTER QUEST ---
     client.register_quest(
         &quest_id,
@@ -40,6 +40,8 @@ fn test_full_quest_lifecycle_events() {
         &reward_amount,
         &verifier,
         &deadline,
+        &10,
+        &None,
     );
 
     // Verify Register Event
@@ -64,9 +66,32 @@ fn test_full_quest_lifecycle_events() {
     assert_eq!(verifier_data, verifier);
     assert_eq!(deadline_data, deadline);
 
+    // --- STEP 1b: FUND QUEST ---
+    // Creator escrows reward_amount * max_claims so rewards can be paid later
+    let fund_amount = 1000i128;
+    token_admin_client.mint(&creator, &fund_amount);
+    client.fund_quest(&quest_id, &creator);
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+
+    // Topics: [EventName, QuestID, Funder]
+    let t_name: Symbol = topics.get(0).unwrap().into_val(&env);
+    let t_id: Symbol = topics.get(1).unwrap().into_val(&env);
+    let t_funder: Address = topics.get(2).unwrap().into_val(&env);
+
+    assert_eq!(t_name, symbol_short!("q_funded"));
+    assert_eq!(t_id, quest_id);
+    assert_eq!(t_funder, creator);
+
+    // Verify Data: (reward_asset, amount)
+    let (funded_asset, funded_amount): (Address, i128) = data.into_val(&env);
+    assert_eq!(funded_asset, token_address);
+    assert_eq!(funded_amount, fund_amount);
+
     // --- STEP 2: SUBMIT PROOF ---
     let proof_hash = BytesN::from_array(&env, &[0u8; 32]);
-    client.submit_proof(&quest_id, &user, &proof_hash);
+    client.submit_proof(&quest_id, &user, &0, &proof_hash, &Vec::new(&env));
 
     let events = env.events().all();
     let (_, topics, _) = events.last().unwrap();
@@ -114,10 +139,14 @@ fn test_full_quest_lifecycle_events() {
     assert_eq!(t_id, quest_id);
     assert_eq!(t_sub, user);
 
-    // Verify Data: (reward_asset, reward_amount)
-    let (claimed_asset, claimed_amount): (Address, i128) = data.into_val(&env);
+    // Verify Data: [(reward_asset, gross, fee, net)] with a single leg
+    let legs: Vec<(Address, i128, i128, i128)> = data.into_val(&env);
+    assert_eq!(legs.len(), 1);
+    let (claimed_asset, gross, fee, net) = legs.get(0).unwrap();
     assert_eq!(claimed_asset, token_address);
-    assert_eq!(claimed_amount, reward_amount);
+    assert_eq!(gross, reward_amount);
+    assert_eq!(fee, 0);
+    assert_eq!(net, reward_amount);
     
     // Verify the XP awarded event (last event)
     let (_, topics, data) = events.last().unwrap();
@@ -0,0 +1,177 @@
+#![cfg(test)]
+
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, BytesN, Env, Symbol, Vec};
+
+extern crate earn_quest;
+use earn_quest::errors::Error;
+use earn_quest::types::Milestone;
+
+mod common;
+use common::{setup_funded_quest, FundedQuest};
+
+//================================================================================
+// Test Setup
+//================================================================================
+
+/// 30% / 30% / 40% of the per-claim reward.
+fn milestones(env: &Env) -> Vec<Milestone> {
+    vec![
+        env,
+        Milestone { share_bps: 3_000 },
+        Milestone { share_bps: 3_000 },
+        Milestone { share_bps: 4_000 },
+    ]
+}
+
+/// Register a staged quest paying 1000 per claim for up to 2 claims, and fund it.
+fn setup_staged_quest<'a>(env: &'a Env, quest_id: &Symbol) -> FundedQuest<'a> {
+    let f = setup_funded_quest(env, quest_id, 1000, 2);
+    f.client.set_milestones(quest_id, &f.creator, &milestones(env));
+    f
+}
+
+fn deliver(env: &Env, f: &FundedQuest, quest_id: &Symbol, hunter: &Address, milestone: u32) {
+    let proof = BytesN::from_array(env, &[milestone as u8; 32]);
+    f.client.submit_proof(quest_id, hunter, &milestone, &proof, &Vec::new(env));
+    f.client.approve_milestone(quest_id, hunter, &milestone, &f.verifier);
+}
+
+//================================================================================
+// Milestone Tests
+//================================================================================
+
+#[test]
+fn test_each_approved_milestone_paid_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("MS1");
+    let f = setup_staged_quest(&env, &quest_id);
+    let hunter = Address::generate(&env);
+
+    deliver(&env, &f, &quest_id, &hunter, 0);
+    f.client.claim_reward(&quest_id, &hunter);
+    assert_eq!(f.token_client.balance(&hunter), 300);
+
+    let res = f.client.try_claim_reward(&quest_id, &hunter);
+    assert_eq!(res, Err(Ok(Error::InvalidStatusTransition)));
+
+    // Later milestones can be claimed together; the last one completes the claim
+    deliver(&env, &f, &quest_id, &hunter, 1);
+    deliver(&env, &f, &quest_id, &hunter, 2);
+    f.client.claim_reward(&quest_id, &hunter);
+    assert_eq!(f.token_client.balance(&hunter), 1000);
+    assert_eq!(f.client.get_user_stats(&hunter).xp, 100);
+
+    let res = f.client.try_claim_reward(&quest_id, &hunter);
+    assert_eq!(res, Err(Ok(Error::AlreadyClaimed)));
+}
+
+#[test]
+fn test_milestones_delivered_in_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("MS2");
+    let f = setup_staged_quest(&env, &quest_id);
+    let hunter = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+
+    let res = f.client.try_submit_proof(&quest_id, &hunter, &1, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::MilestoneOutOfOrder)));
+
+    let res = f.client.try_submit_proof(&quest_id, &hunter, &3, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::MilestoneNotFound)));
+
+    // Submitted but not yet approved still blocks the next milestone
+    f.client.submit_proof(&quest_id, &hunter, &0, &proof, &Vec::new(&env));
+    let res = f.client.try_submit_proof(&quest_id, &hunter, &1, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::MilestoneOutOfOrder)));
+}
+
+#[test]
+fn test_milestones_approved_individually() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("MS3");
+    let f = setup_staged_quest(&env, &quest_id);
+    let hunter = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+
+    f.client.submit_proof(&quest_id, &hunter, &0, &proof, &Vec::new(&env));
+    let res = f.client.try_approve_submission(&quest_id, &hunter, &f.verifier);
+    assert_eq!(res, Err(Ok(Error::MilestoneNotFound)));
+
+    f.client.approve_milestone(&quest_id, &hunter, &0, &f.verifier);
+    let res = f.client.try_approve_milestone(&quest_id, &hunter, &0, &f.verifier);
+    assert_eq!(res, Err(Ok(Error::InvalidStatusTransition)));
+
+    // An approved milestone's proof is final
+    let res = f.client.try_submit_proof(&quest_id, &hunter, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::InvalidStatusTransition)));
+}
+
+#[test]
+fn test_cancel_keeps_approved_milestones_claimable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("MS4");
+    let f = setup_staged_quest(&env, &quest_id);
+    let hunter = Address::generate(&env);
+
+    deliver(&env, &f, &quest_id, &hunter, 0);
+    assert_eq!(f.client.cancel_quest(&quest_id, &f.creator), 1700);
+
+    f.client.claim_reward(&quest_id, &hunter);
+    assert_eq!(f.token_client.balance(&hunter), 300);
+    assert_eq!(f.token_client.balance(&f.contract_id), 0);
+}
+
+#[test]
+fn test_partial_payouts_capped_at_max_claims() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("MS6");
+    let f = setup_staged_quest(&env, &quest_id);
+    let hunters = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+
+    deliver(&env, &f, &quest_id, &hunters[0], 0);
+    deliver(&env, &f, &quest_id, &hunters[1], 0);
+
+    // Two hunters already hold the quest's two claims
+    let proof = BytesN::from_array(&env, &[0u8; 32]);
+    f.client.submit_proof(&quest_id, &hunters[2], &0, &proof, &Vec::new(&env));
+    let res = f.client.try_approve_milestone(&quest_id, &hunters[2], &0, &f.verifier);
+    assert_eq!(res, Err(Ok(Error::QuestFull)));
+
+    // Hunters already in progress keep approving later milestones
+    deliver(&env, &f, &quest_id, &hunters[0], 1);
+    deliver(&env, &f, &quest_id, &hunters[0], 2);
+    for hunter in hunters.iter().take(2) {
+        f.client.claim_reward(&quest_id, hunter);
+    }
+    assert_eq!(f.token_client.balance(&hunters[0]), 1000);
+    assert_eq!(f.token_client.balance(&hunters[1]), 300);
+    assert_eq!(f.token_client.balance(&hunters[2]), 0);
+}
+
+#[test]
+fn test_set_milestones_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("MS5");
+    let f = setup_staged_quest(&env, &quest_id);
+
+    let short = vec![&env, Milestone { share_bps: 5_000 }, Milestone { share_bps: 4_000 }];
+    let res = f.client.try_set_milestones(&quest_id, &f.creator, &short);
+    assert_eq!(res, Err(Ok(Error::InvalidShares)));
+
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    f.client.submit_proof(&quest_id, &Address::generate(&env), &0, &proof, &Vec::new(&env));
+    let res = f.client.try_set_milestones(&quest_id, &f.creator, &milestones(&env));
+    assert_eq!(res, Err(Ok(Error::QuestTermsLocked)));
+}
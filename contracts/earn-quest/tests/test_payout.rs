@@ -1,12 +1,17 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env, symbol_short, BytesN};
+use soroban_sdk::{testutils::{Address as _, Events, IssuerFlags}, vec, Address, Env, IntoVal, Vec, symbol_short, BytesN};
 use soroban_sdk::token::{StellarAssetClient, TokenClient};
 
 // Import from the library
 extern crate earn_quest;
+use earn_quest::errors::Error;
+use earn_quest::types::RewardLeg;
 use earn_quest::{EarnQuestContract, EarnQuestContractClient};
 
+mod common;
+use common::allow_asset;
+
 #[test]
 fn test_payout_success() {
     let env = Env::default();
@@ -22,9 +27,7 @@ fn test_payout_success() {
     let token_admin_client = StellarAssetClient::new(&env, &token_contract);
     let token_client = TokenClient::new(&env, &token_contract);
     
-    // Fund the contract with enough tokens for payout
     let reward_amount = 100i128;
-    token_admin_client.mint(&contract_id, &1000); 
     
     // 2. Setup Quest
     let creator = Address::generate(&env);
@@ -33,6 +36,8 @@ fn test_payout_success() {
     
     let quest_id = symbol_short!("Q1");
     
+    allow_asset(&env, &client, &token_contract);
+    
     client.register_quest(
         &quest_id,
         &creator,
@@ -40,11 +45,20 @@ fn test_payout_success() {
         &reward_amount,
         &verifier,
         &10000,
+        &10,
+        &None,
     );
     
+    // Creator escrows reward_amount * max_claims for this quest
+    token_admin_client.mint(&creator, &1000);
+    client.fund_quest(&quest_id, &creator);
+    assert_eq!(token_client.balance(&creator), 0);
+    assert_eq!(token_client.balance(&contract_id), 1000);
+    assert_eq!(client.get_quest_escrow(&quest_id), 1000);
+    
     // 3. Submit Proof
     let proof = BytesN::from_array(&env, &[1u8; 32]);
-    client.submit_proof(&quest_id, &submitter, &proof);
+    client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
     
     // 4. Approve Submission
     client.approve_submission(&quest_id, &submitter, &verifier);
@@ -57,6 +71,7 @@ fn test_payout_success() {
     
     let post_balance = token_client.balance(&submitter);
     assert_eq!(post_balance, 100);
+    assert_eq!(client.get_quest_escrow(&quest_id), 900);
 }
 
 #[test]
@@ -77,6 +92,8 @@ fn test_insufficient_balance() {
     let submitter = Address::generate(&env);
     let quest_id = symbol_short!("Q2");
     
+    allow_asset(&env, &client, &token_contract);
+    
     client.register_quest(
         &quest_id,
         &creator,
@@ -84,15 +101,17 @@ fn test_insufficient_balance() {
         &100,
         &verifier,
         &10000,
+        &10,
+        &None,
     );
     
     let proof = BytesN::from_array(&env, &[1u8; 32]);
-    client.submit_proof(&quest_id, &submitter, &proof);
+    client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
     client.approve_submission(&quest_id, &submitter, &verifier);
     
     // Claim should fail with InsufficientBalance
     let res = client.try_claim_reward(&quest_id, &submitter);
-    assert!(res.is_err(), "Expected claim to fail due to insufficient balance");
+    assert_eq!(res, Err(Ok(Error::InsufficientBalance)));
 }
 
 #[test]
@@ -107,13 +126,14 @@ fn test_double_claim_prevention() {
     let token_contract_obj = env.register_stellar_asset_contract_v2(admin.clone());
     let token_contract = token_contract_obj.address();
     let token_admin_client = StellarAssetClient::new(&env, &token_contract);
-    token_admin_client.mint(&contract_id, &1000); 
     
     let creator = Address::generate(&env);
     let verifier = Address::generate(&env);
     let submitter = Address::generate(&env);
     let quest_id = symbol_short!("Q3");
     
+    allow_asset(&env, &client, &token_contract);
+    
     client.register_quest(
         &quest_id,
         &creator,
@@ -121,10 +141,14 @@ fn test_double_claim_prevention() {
         &100,
         &verifier,
         &10000,
+        &10,
+        &None,
     );
+    token_admin_client.mint(&creator, &1000);
+    client.fund_quest(&quest_id, &creator);
     
     let proof = BytesN::from_array(&env, &[1u8; 32]);
-    client.submit_proof(&quest_id, &submitter, &proof);
+    client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
     client.approve_submission(&quest_id, &submitter, &verifier);
     
     // First claim
@@ -132,5 +156,452 @@ fn test_double_claim_prevention() {
     
     // Second claim should fail with AlreadyClaimed
     let res = client.try_claim_reward(&quest_id, &submitter);
-    assert!(res.is_err(), "Expected second claim to fail");
+    assert_eq!(res, Err(Ok(Error::AlreadyClaimed)));
+}
+
+#[test]
+fn test_escrow_isolated_between_quests() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let token_contract_obj = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_contract = token_contract_obj.address();
+    let token_admin_client = StellarAssetClient::new(&env, &token_contract);
+    let token_client = TokenClient::new(&env, &token_contract);
+    
+    let funded_creator = Address::generate(&env);
+    let unfunded_creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    
+    // Quest A is fully funded by its creator
+    allow_asset(&env, &client, &token_contract);
+    let funded_id = symbol_short!("QA");
+    client.register_quest(&funded_id, &funded_creator, &token_contract, &100, &verifier, &10000, &2, &None);
+    token_admin_client.mint(&funded_creator, &200);
+    client.fund_quest(&funded_id, &funded_creator);
+    
+    // Quest B is registered but never funded
+    let unfunded_id = symbol_short!("QB");
+    client.register_quest(&unfunded_id, &unfunded_creator, &token_contract, &100, &verifier, &10000, &2, &None);
+    assert_eq!(client.get_quest_escrow(&unfunded_id), 0);
+    
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&unfunded_id, &submitter, &0, &proof, &Vec::new(&env));
+    client.approve_submission(&unfunded_id, &submitter, &verifier);
+    
+    // Quest B cannot dip into Quest A's deposit even though the contract holds funds
+    let res = client.try_claim_reward(&unfunded_id, &submitter);
+    assert_eq!(res, Err(Ok(Error::InsufficientBalance)));
+    assert_eq!(token_client.balance(&contract_id), 200);
+    assert_eq!(client.get_quest_escrow(&funded_id), 200);
+}
+
+#[test]
+fn test_fund_quest_twice_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let token_contract_obj = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_contract = token_contract_obj.address();
+    let token_admin_client = StellarAssetClient::new(&env, &token_contract);
+    
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let quest_id = symbol_short!("Q4");
+    
+    allow_asset(&env, &client, &token_contract);
+    
+    client.register_quest(&quest_id, &creator, &token_contract, &100, &verifier, &10000, &5, &None);
+    token_admin_client.mint(&creator, &1000);
+    
+    assert_eq!(client.fund_quest(&quest_id, &creator), 500);
+    
+    let res = client.try_fund_quest(&quest_id, &creator);
+    assert_eq!(res, Err(Ok(Error::AlreadyFunded)));
+    assert_eq!(client.get_quest_escrow(&quest_id), 500);
+}
+
+#[test]
+fn test_fund_quest_by_non_creator_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let token_contract_obj = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_contract = token_contract_obj.address();
+    let token_admin_client = StellarAssetClient::new(&env, &token_contract);
+    
+    let creator = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let quest_id = symbol_short!("Q5");
+    
+    allow_asset(&env, &client, &token_contract);
+    
+    client.register_quest(&quest_id, &creator, &token_contract, &100, &verifier, &10000, &5, &None);
+    token_admin_client.mint(&stranger, &1000);
+    
+    let res = client.try_fund_quest(&quest_id, &stranger);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_claims_capped_at_max_claims() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let token_contract_obj = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_contract = token_contract_obj.address();
+    let token_admin_client = StellarAssetClient::new(&env, &token_contract);
+    
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let quest_id = symbol_short!("Q6");
+    
+    allow_asset(&env, &client, &token_contract);
+    
+    client.register_quest(&quest_id, &creator, &token_contract, &100, &verifier, &10000, &1, &None);
+    token_admin_client.mint(&creator, &100);
+    client.fund_quest(&quest_id, &creator);
+    
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&quest_id, &first, &0, &proof, &Vec::new(&env));
+    client.submit_proof(&quest_id, &second, &0, &proof, &Vec::new(&env));
+    client.approve_submission(&quest_id, &first, &verifier);
+    
+    // The only claim is already taken by a pending approval
+    let res = client.try_approve_submission(&quest_id, &second, &verifier);
+    assert_eq!(res, Err(Ok(Error::QuestFull)));
+    
+    client.claim_reward(&quest_id, &first);
+    assert_eq!(client.get_quest_escrow(&quest_id), 0);
+    
+    let res = client.try_approve_submission(&quest_id, &second, &verifier);
+    assert_eq!(res, Err(Ok(Error::QuestFull)));
+}
+
+#[test]
+fn test_reconcile_tracks_escrow_liabilities() {
+    let env = Env::default();
+    env.mock_all_auths();
+    
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(&env, &contract_id);
+    
+    let admin = Address::generate(&env);
+    let token_contract_obj = env.register_stellar_asset_contract_v2(admin.clone());
+    let token_contract = token_contract_obj.address();
+    let token_admin_client = StellarAssetClient::new(&env, &token_contract);
+    
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let quest_id = symbol_short!("Q7");
+    
+    let report = client.reconcile(&token_contract);
+    assert_eq!((report.balance, report.liabilities, report.surplus), (0, 0, 0));
+    
+    allow_asset(&env, &client, &token_contract);
+    // Funding adds to both balance and liabilities
+    client.register_quest(&quest_id, &creator, &token_contract, &100, &verifier, &10000, &3, &None);
+    token_admin_client.mint(&creator, &300);
+    client.fund_quest(&quest_id, &creator);
+    let report = client.reconcile(&token_contract);
+    assert_eq!((report.balance, report.liabilities, report.surplus), (300, 300, 0));
+    
+    // Approved-but-unclaimed rewards stay in escrow
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
+    client.approve_submission(&quest_id, &submitter, &verifier);
+    let report = client.reconcile(&token_contract);
+    assert_eq!(report.liabilities, 300);
+    
+    // Claims release liabilities
+    client.claim_reward(&quest_id, &submitter);
+    let report = client.reconcile(&token_contract);
+    assert_eq!((report.balance, report.liabilities, report.surplus), (200, 200, 0));
+    
+    // Direct transfers to the contract are surplus
+    token_admin_client.mint(&contract_id, &50);
+    let report = client.reconcile(&token_contract);
+    assert_eq!((report.balance, report.liabilities, report.surplus), (250, 200, 50));
+    
+    // Refunds release liabilities
+    client.cancel_quest(&quest_id, &creator);
+    let report = client.reconcile(&token_contract);
+    assert_eq!((report.balance, report.liabilities, report.surplus), (50, 0, 50));
+}
+
+#[test]
+fn test_platform_fee_routed_to_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    client.initialize(&admin);
+    client.set_platform_fee(&admin, &250, &treasury);
+    assert_eq!(client.get_platform_fee(), 250);
+    assert_eq!(client.get_treasury(), Some(treasury.clone()));
+
+    let token_contract = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let token_admin_client = StellarAssetClient::new(&env, &token_contract);
+    let token_client = TokenClient::new(&env, &token_contract);
+
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let quest_id = symbol_short!("FEE");
+
+    allow_asset(&env, &client, &token_contract);
+
+    client.register_quest(&quest_id, &creator, &token_contract, &1000, &verifier, &10000, &1, &None);
+    token_admin_client.mint(&creator, &1000);
+    client.fund_quest(&quest_id, &creator);
+
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
+    client.approve_submission(&quest_id, &submitter, &verifier);
+    client.claim_reward(&quest_id, &submitter);
+
+    // 2.5% of the gross reward goes to the treasury
+    assert_eq!(token_client.balance(&treasury), 25);
+    assert_eq!(token_client.balance(&submitter), 975);
+    assert_eq!(client.get_quest_escrow(&quest_id), 0);
+    assert_eq!(client.reconcile(&token_contract).liabilities, 0);
+}
+
+#[test]
+fn test_platform_fee_capped_and_admin_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    client.initialize(&admin);
+
+    let result = client.try_set_platform_fee(&admin, &1001, &treasury);
+    assert_eq!(result, Err(Ok(Error::FeeTooHigh)));
+
+    let result = client.try_set_platform_fee(&Address::generate(&env), &100, &treasury);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    assert_eq!(client.get_platform_fee(), 0);
+    assert_eq!(client.get_treasury(), None);
+}
+
+/// Register a quest paying 100 of a primary token plus 40 of a bundled token, 2 claims.
+fn setup_bundle_quest<'a>(
+    env: &'a Env,
+) -> (EarnQuestContractClient<'a>, Address, Address, StellarAssetClient<'a>, Address, Address) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let primary = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    // The bonus token can revoke authorization, so a leg transfer can be made to fail
+    let bonus_sac = env.register_stellar_asset_contract_v2(admin.clone());
+    bonus_sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    let bonus = bonus_sac.address();
+    allow_asset(env, &client, &primary);
+    allow_asset(env, &client, &bonus);
+
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+    let quest_id = symbol_short!("BNDL");
+    client.register_quest(&quest_id, &creator, &primary, &100, &verifier, &10000, &2, &None);
+
+    let legs = vec![env, RewardLeg { asset: bonus.clone(), amount: 40 }];
+    client.set_reward_bundle(&quest_id, &creator, &legs);
+
+    StellarAssetClient::new(env, &primary).mint(&creator, &200);
+    let bonus_admin = StellarAssetClient::new(env, &bonus);
+    bonus_admin.mint(&creator, &80);
+
+    (client, primary, bonus, bonus_admin, creator, verifier)
+}
+
+#[test]
+fn test_reward_bundle_pays_every_leg() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, primary, bonus, _, creator, verifier) = setup_bundle_quest(&env);
+    let quest_id = symbol_short!("BNDL");
+    let submitter = Address::generate(&env);
+
+    // Funding escrows every leg for every claim
+    client.fund_quest(&quest_id, &creator);
+    assert_eq!(client.get_asset_escrow(&quest_id, &primary), 200);
+    assert_eq!(client.get_asset_escrow(&quest_id, &bonus), 80);
+
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
+    client.approve_submission(&quest_id, &submitter, &verifier);
+    client.claim_reward(&quest_id, &submitter);
+
+    let events = env.events().all();
+    let (_, _, data) = events.get(events.len() - 2).unwrap();
+    let legs: Vec<(Address, i128, i128, i128)> = data.into_val(&env);
+    assert_eq!(
+        legs,
+        vec![&env, (primary.clone(), 100, 0, 100), (bonus.clone(), 40, 0, 40)]
+    );
+
+    assert_eq!(TokenClient::new(&env, &primary).balance(&submitter), 100);
+    assert_eq!(TokenClient::new(&env, &bonus).balance(&submitter), 40);
+    assert_eq!(client.get_asset_escrow(&quest_id, &bonus), 40);
+
+    // Cancelling refunds the remaining escrow of every leg
+    client.cancel_quest(&quest_id, &creator);
+    assert_eq!(TokenClient::new(&env, &primary).balance(&creator), 100);
+    assert_eq!(TokenClient::new(&env, &bonus).balance(&creator), 40);
+}
+
+#[test]
+fn test_reward_bundle_claim_is_atomic() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, primary, bonus, bonus_admin, creator, verifier) = setup_bundle_quest(&env);
+    let quest_id = symbol_short!("BNDL");
+    let submitter = Address::generate(&env);
+    client.fund_quest(&quest_id, &creator);
+
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
+    client.approve_submission(&quest_id, &submitter, &verifier);
+
+    // The bonus leg cannot be delivered, so nothing is paid
+    bonus_admin.set_authorized(&submitter, &false);
+    let result = client.try_claim_reward(&quest_id, &submitter);
+    assert_eq!(result, Err(Ok(Error::TransferFailed)));
+
+    assert_eq!(TokenClient::new(&env, &primary).balance(&submitter), 0);
+    assert_eq!(client.get_asset_escrow(&quest_id, &primary), 200);
+    assert_eq!(client.get_asset_escrow(&quest_id, &bonus), 80);
+}
+
+#[test]
+fn test_reward_bundle_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, primary, bonus, _, creator, _) = setup_bundle_quest(&env);
+    let quest_id = symbol_short!("BNDL");
+
+    // The primary asset cannot be repeated as a leg
+    let legs = vec![&env, RewardLeg { asset: primary, amount: 40 }];
+    let result = client.try_set_reward_bundle(&quest_id, &creator, &legs);
+    assert_eq!(result, Err(Ok(Error::InvalidAsset)));
+
+    // Leg assets must be allowlisted
+    let legs = vec![&env, RewardLeg { asset: Address::generate(&env), amount: 40 }];
+    let result = client.try_set_reward_bundle(&quest_id, &creator, &legs);
+    assert_eq!(result, Err(Ok(Error::InvalidAsset)));
+
+    // The bundle is locked once funded
+    client.fund_quest(&quest_id, &creator);
+    let legs = vec![&env, RewardLeg { asset: bonus, amount: 10 }];
+    let result = client.try_set_reward_bundle(&quest_id, &creator, &legs);
+    assert_eq!(result, Err(Ok(Error::AlreadyFunded)));
+}
+
+/// Register a quest paying 100 per claim in a token the contract administers.
+fn setup_mint_quest<'a>(env: &'a Env) -> (EarnQuestContractClient<'a>, Address, Address, Address, Address) {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let issuer = Address::generate(env);
+    let verifier = Address::generate(env);
+
+    let token = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    StellarAssetClient::new(env, &token).set_admin(&contract_id);
+
+    client.initialize(&admin);
+    client.allow_asset(&admin, &token, &1, &1000);
+    client.set_mintable_asset(&admin, &token, &issuer);
+
+    client.register_quest(&symbol_short!("MINT"), &issuer, &token, &100, &verifier, &10000, &10, &None);
+
+    (client, token, admin, issuer, verifier)
+}
+
+#[test]
+fn test_mint_mode_mints_reward_on_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, token, _, issuer, verifier) = setup_mint_quest(&env);
+    let quest_id = symbol_short!("MINT");
+    client.set_mint_mode(&quest_id, &issuer, &150);
+
+    // No escrow is needed: the reward is minted straight to the hunter
+    let submitter = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
+    client.approve_submission(&quest_id, &submitter, &verifier);
+    client.claim_reward(&quest_id, &submitter);
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&submitter), 100);
+    assert_eq!(client.get_quest_escrow(&quest_id), 0);
+    assert_eq!(client.reconcile(&token).liabilities, 0);
+
+    // A second claim would exceed the per-quest mint cap
+    let second = Address::generate(&env);
+    client.submit_proof(&quest_id, &second, &0, &proof, &Vec::new(&env));
+    client.approve_submission(&quest_id, &second, &verifier);
+    let result = client.try_claim_reward(&quest_id, &second);
+    assert_eq!(result, Err(Ok(Error::MintCapExceeded)));
+    assert_eq!(token_client.balance(&second), 0);
+}
+
+#[test]
+fn test_mint_mode_requires_registered_issuer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (client, token, admin, issuer, verifier) = setup_mint_quest(&env);
+
+    // Creators other than the registered issuer cannot mint the asset
+    let stranger = Address::generate(&env);
+    client.register_quest(&symbol_short!("MINT2"), &stranger, &token, &100, &verifier, &10000, &1, &None);
+    let result = client.try_set_mint_mode(&symbol_short!("MINT2"), &stranger, &100);
+    assert_eq!(result, Err(Ok(Error::NotMintable)));
+
+    // Minting quests are never funded from escrow
+    client.set_mint_mode(&symbol_short!("MINT"), &issuer, &1000);
+    let result = client.try_fund_quest(&symbol_short!("MINT"), &issuer);
+    assert_eq!(result, Err(Ok(Error::AlreadyFunded)));
+
+    let result = client.try_set_mintable_asset(&stranger, &token, &stranger);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+    let result = client.try_set_mintable_asset(&admin, &Address::generate(&env), &issuer);
+    assert_eq!(result, Err(Ok(Error::InvalidAsset)));
 }
@@ -0,0 +1,259 @@
+#![cfg(test)]
+
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+extern crate earn_quest;
+use earn_quest::errors::Error;
+use earn_quest::types::{PoolAllowance, RewardLeg};
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+//================================================================================
+// Test Setup
+//================================================================================
+
+struct PoolFixture<'a> {
+    contract_id: Address,
+    client: EarnQuestContractClient<'a>,
+    token: Address,
+    token_admin: StellarAssetClient<'a>,
+    token_client: TokenClient<'a>,
+    admin: Address,
+    owner: Address,
+    verifier: Address,
+}
+
+/// Register a contract and an allowlisted token, and a pool "POOL" holding 300.
+fn setup_pool(env: &Env) -> PoolFixture<'_> {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let token_admin = StellarAssetClient::new(env, &token);
+    let token_client = TokenClient::new(env, &token);
+
+    client.initialize(&admin);
+    client.allow_asset(&admin, &token, &1, &earn_quest::validation::MAX_REWARD_AMOUNT);
+
+    let owner = Address::generate(env);
+    let verifier = Address::generate(env);
+
+    client.create_pool(&symbol_short!("POOL"), &owner, &token);
+    token_admin.mint(&owner, &300);
+    client.deposit_to_pool(&symbol_short!("POOL"), &owner, &300);
+
+    PoolFixture {
+        contract_id,
+        client,
+        token,
+        token_admin,
+        token_client,
+        admin,
+        owner,
+        verifier,
+    }
+}
+
+/// Register a quest paying 100 per claim from the pool, owned by the pool owner.
+fn register_pool_quest(f: &PoolFixture, quest_id: &Symbol, cap: i128) {
+    let pool_id = symbol_short!("POOL");
+    f.client.register_quest(
+        quest_id,
+        &f.owner,
+        &f.token,
+        &100,
+        &f.verifier,
+        &10000,
+        &10,
+        &Some(pool_id.clone()),
+    );
+    f.client.set_pool_cap(&pool_id, &f.owner, quest_id, &cap);
+}
+
+fn claim(env: &Env, f: &PoolFixture, quest_id: &Symbol, hunter: &Address) -> Result<(), Error> {
+    let proof = BytesN::from_array(env, &[1u8; 32]);
+    f.client.submit_proof(quest_id, hunter, &0, &proof, &Vec::new(env));
+    f.client.approve_submission(quest_id, hunter, &f.verifier);
+    f.client
+        .try_claim_reward(quest_id, hunter)
+        .map(|_| ())
+        .map_err(|e| e.unwrap())
+}
+
+//================================================================================
+// Reward Pool Tests
+//================================================================================
+
+#[test]
+fn test_pool_deposit_and_withdraw() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let f = setup_pool(&env);
+    let pool_id = symbol_short!("POOL");
+    assert_eq!(f.client.get_pool(&pool_id).balance, 300);
+    assert_eq!(f.token_client.balance(&f.contract_id), 300);
+    assert_eq!(f.client.reconcile(&f.token).liabilities, 300);
+
+    assert_eq!(f.client.withdraw_from_pool(&pool_id, &f.owner, &120), 180);
+    assert_eq!(f.token_client.balance(&f.owner), 120);
+    assert_eq!(f.client.reconcile(&f.token).liabilities, 180);
+
+    let res = f.client.try_withdraw_from_pool(&pool_id, &f.owner, &181);
+    assert_eq!(res, Err(Ok(Error::PoolExhausted)));
+
+    let res = f.client.try_withdraw_from_pool(&pool_id, &Address::generate(&env), &10);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_quests_draw_from_shared_pool() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let f = setup_pool(&env);
+    let pool_id = symbol_short!("POOL");
+    let quest_a = symbol_short!("PQA");
+    let quest_b = symbol_short!("PQB");
+    register_pool_quest(&f, &quest_a, 1000);
+    register_pool_quest(&f, &quest_b, 1000);
+
+    let hunter_a = Address::generate(&env);
+    let hunter_b = Address::generate(&env);
+    claim(&env, &f, &quest_a, &hunter_a).unwrap();
+    claim(&env, &f, &quest_b, &hunter_b).unwrap();
+
+    assert_eq!(f.token_client.balance(&hunter_a), 100);
+    assert_eq!(f.token_client.balance(&hunter_b), 100);
+    assert_eq!(f.client.get_pool(&pool_id).balance, 100);
+    assert_eq!(
+        f.client.get_pool_allowance(&pool_id, &quest_a),
+        PoolAllowance { cap: 1000, spent: 100 }
+    );
+    assert_eq!(f.client.reconcile(&f.token).liabilities, 100);
+}
+
+#[test]
+fn test_pool_exhaustion_and_quest_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let f = setup_pool(&env);
+    let quest_id = symbol_short!("PQC");
+    register_pool_quest(&f, &quest_id, 100);
+
+    claim(&env, &f, &quest_id, &Address::generate(&env)).unwrap();
+
+    // The quest's cap is used up even though the pool still holds 200
+    let res = claim(&env, &f, &quest_id, &Address::generate(&env));
+    assert_eq!(res, Err(Error::PoolExhausted));
+
+    // Raising the cap lets the quest draw until the pool runs dry
+    f.client
+        .set_pool_cap(&symbol_short!("POOL"), &f.owner, &quest_id, &1000);
+    claim(&env, &f, &quest_id, &Address::generate(&env)).unwrap();
+    claim(&env, &f, &quest_id, &Address::generate(&env)).unwrap();
+    let res = claim(&env, &f, &quest_id, &Address::generate(&env));
+    assert_eq!(res, Err(Error::PoolExhausted));
+}
+
+#[test]
+fn test_register_pool_quest_defaults_cap_to_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let f = setup_pool(&env);
+    let pool_id = symbol_short!("POOL");
+    let quest_id = symbol_short!("PQG");
+    f.client.register_quest(
+        &quest_id,
+        &f.owner,
+        &f.token,
+        &100,
+        &f.verifier,
+        &10000,
+        &3,
+        &Some(pool_id.clone()),
+    );
+
+    assert_eq!(
+        f.client.get_pool_allowance(&pool_id, &quest_id),
+        PoolAllowance { cap: 300, spent: 0 }
+    );
+    claim(&env, &f, &quest_id, &Address::generate(&env)).unwrap();
+
+    // Cap changes are told apart from attachments
+    f.client.set_pool_cap(&pool_id, &f.owner, &quest_id, &500);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("pool_cap"), pool_id.clone(), quest_id.clone()).into_val(&env));
+    let (previous_cap, cap): (i128, i128) = data.into_val(&env);
+    assert_eq!((previous_cap, cap), (300, 500));
+}
+
+#[test]
+fn test_pool_quest_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let f = setup_pool(&env);
+    let pool_id = symbol_short!("POOL");
+
+    // The pool must exist and pay in the quest's asset
+    let res = f.client.try_register_quest(
+        &symbol_short!("PQD"),
+        &f.owner,
+        &f.token,
+        &100,
+        &f.verifier,
+        &10000,
+        &1,
+        &Some(symbol_short!("NOPE")),
+    );
+    assert_eq!(res, Err(Ok(Error::PoolNotFound)));
+
+    let other_token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    f.client.allow_asset(&f.admin, &other_token, &1, &earn_quest::validation::MAX_REWARD_AMOUNT);
+    let res = f.client.try_register_quest(
+        &symbol_short!("PQD"),
+        &f.owner,
+        &other_token,
+        &100,
+        &f.verifier,
+        &10000,
+        &1,
+        &Some(pool_id.clone()),
+    );
+    assert_eq!(res, Err(Ok(Error::InvalidAsset)));
+
+    // Pool-backed quests are never funded directly, sponsored or bundled
+    let pooled = symbol_short!("PQE");
+    register_pool_quest(&f, &pooled, 100);
+    let res = f.client.try_fund_quest(&pooled, &f.owner);
+    assert_eq!(res, Err(Ok(Error::AlreadyFunded)));
+    f.token_admin.mint(&f.verifier, &100);
+    let res = f.client.try_sponsor_quest(&pooled, &f.verifier, &100);
+    assert_eq!(res, Err(Ok(Error::AlreadyFunded)));
+    let legs = vec![&env, RewardLeg { asset: f.token.clone(), amount: 1 }];
+    let res = f.client.try_set_reward_bundle(&pooled, &f.owner, &legs);
+    assert_eq!(res, Err(Ok(Error::AlreadyFunded)));
+
+    // Only the pool owner changes caps, and only of its own quests
+    let res = f
+        .client
+        .try_set_pool_cap(&pool_id, &Address::generate(&env), &pooled, &100);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+
+    let funded = symbol_short!("PQF");
+    f.client
+        .register_quest(&funded, &f.owner, &f.token, &100, &f.verifier, &10000, &1, &None);
+    let res = f.client.try_set_pool_cap(&pool_id, &f.owner, &funded, &100);
+    assert_eq!(res, Err(Ok(Error::PoolNotFound)));
+
+    let res = f.client.try_create_pool(&pool_id, &f.owner, &f.token);
+    assert_eq!(res, Err(Ok(Error::PoolAlreadyExists)));
+}
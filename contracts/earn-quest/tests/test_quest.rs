@@ -0,0 +1,1105 @@
+#![cfg(test)]
+
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+extern crate earn_quest;
+use earn_quest::errors::Error;
+use earn_quest::types::{RewardTier, Sponsorship};
+use earn_quest::{EarnQuestContract, EarnQuestContractClient};
+
+mod common;
+use common::allow_asset;
+
+//================================================================================
+// Test Setup
+//================================================================================
+
+struct QuestFixture<'a> {
+    contract_id: Address,
+    client: EarnQuestContractClient<'a>,
+    token: Address,
+    token_admin: StellarAssetClient<'a>,
+    token_client: TokenClient<'a>,
+    creator: Address,
+    verifier: Address,
+}
+
+/// Register a contract and token, and a quest paying 100 per claim for up to 5 claims.
+fn setup_quest<'a>(env: &'a Env, quest_id: &Symbol) -> QuestFixture<'a> {
+    let contract_id = env.register_contract(None, EarnQuestContract);
+    let client = EarnQuestContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(admin).address();
+    let token_admin = StellarAssetClient::new(env, &token);
+    let token_client = TokenClient::new(env, &token);
+
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+
+    allow_asset(env, &client, &token);
+
+    client.register_quest(quest_id, &creator, &token, &100, &verifier, &10000, &5, &None);
+
+    QuestFixture {
+        contract_id,
+        client,
+        token,
+        token_admin,
+        token_client,
+        creator,
+        verifier,
+    }
+}
+
+fn fund(f: &QuestFixture, quest_id: &Symbol) {
+    f.token_admin.mint(&f.creator, &500);
+    f.client.fund_quest(quest_id, &f.creator);
+}
+
+fn submit_and_approve(env: &Env, f: &QuestFixture, quest_id: &Symbol, submitter: &Address) {
+    let proof = BytesN::from_array(env, &[1u8; 32]);
+    f.client.submit_proof(quest_id, submitter, &0, &proof, &Vec::new(env));
+    f.client.approve_submission(quest_id, submitter, &f.verifier);
+}
+
+//================================================================================
+// Cancellation Tests
+//================================================================================
+
+#[test]
+fn test_cancel_quest_refunds_full_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("CQ1");
+    let f = setup_quest(&env, &quest_id);
+    fund(&f, &quest_id);
+
+    let refunded = f.client.cancel_quest(&quest_id, &f.creator);
+
+    assert_eq!(refunded, 500);
+    assert_eq!(f.token_client.balance(&f.creator), 500);
+    assert_eq!(f.token_client.balance(&f.contract_id), 0);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 0);
+}
+
+#[test]
+fn test_cancel_quest_keeps_approved_rewards_claimable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("CQ2");
+    let f = setup_quest(&env, &quest_id);
+    fund(&f, &quest_id);
+
+    let approved = Address::generate(&env);
+    let pending = Address::generate(&env);
+    submit_and_approve(&env, &f, &quest_id, &approved);
+    let proof = BytesN::from_array(&env, &[2u8; 32]);
+    f.client.submit_proof(&quest_id, &pending, &0, &proof, &Vec::new(&env));
+
+    // One approved reward stays reserved, the rest goes back to the creator
+    let refunded = f.client.cancel_quest(&quest_id, &f.creator);
+    assert_eq!(refunded, 400);
+    assert_eq!(f.token_client.balance(&f.creator), 400);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 100);
+
+    // The approved submitter can still claim after cancellation
+    f.client.claim_reward(&quest_id, &approved);
+    assert_eq!(f.token_client.balance(&approved), 100);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 0);
+
+    // Pending submissions can no longer be approved
+    let res = f
+        .client
+        .try_approve_submission(&quest_id, &pending, &f.verifier);
+    assert_eq!(res, Err(Ok(Error::QuestNotActive)));
+}
+
+#[test]
+fn test_cancel_quest_blocks_new_submissions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("CQ3");
+    let f = setup_quest(&env, &quest_id);
+    f.client.cancel_quest(&quest_id, &f.creator);
+
+    let submitter = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    let res = f.client.try_submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::QuestNotActive)));
+}
+
+#[test]
+fn test_cancel_quest_by_non_creator_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("CQ4");
+    let f = setup_quest(&env, &quest_id);
+    fund(&f, &quest_id);
+
+    let res = f.client.try_cancel_quest(&quest_id, &f.verifier);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 500);
+}
+
+#[test]
+fn test_cancel_quest_twice_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("CQ5");
+    let f = setup_quest(&env, &quest_id);
+    fund(&f, &quest_id);
+
+    f.client.cancel_quest(&quest_id, &f.creator);
+
+    let res = f.client.try_cancel_quest(&quest_id, &f.creator);
+    assert_eq!(res, Err(Ok(Error::InvalidStatusTransition)));
+}
+
+#[test]
+fn test_cancel_quest_emits_event_with_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("CQ6");
+    let f = setup_quest(&env, &quest_id);
+    fund(&f, &quest_id);
+
+    f.client.cancel_quest(&quest_id, &f.creator);
+
+    let events = env.events().all();
+    let (contract, topics, data) = events.last().unwrap();
+    assert_eq!(contract, f.contract_id);
+
+    // Topics: [EventName, QuestID, Creator]
+    let t_name: Symbol = topics.get(0).unwrap().into_val(&env);
+    let t_id: Symbol = topics.get(1).unwrap().into_val(&env);
+    let t_creator: Address = topics.get(2).unwrap().into_val(&env);
+    assert_eq!(t_name, symbol_short!("q_cancel"));
+    assert_eq!(t_id, quest_id);
+    assert_eq!(t_creator, f.creator);
+
+    // Data: (Asset, Refunded Amount)
+    let (asset, refunded): (Address, i128) = data.into_val(&env);
+    assert_eq!(asset, f.token);
+    assert_eq!(refunded, 500);
+}
+
+//================================================================================
+// Expiry Sweep Tests
+//================================================================================
+
+#[test]
+fn test_reclaim_expired_before_window_closes_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("EQ1");
+    let f = setup_quest(&env, &quest_id);
+    fund(&f, &quest_id);
+
+    // Past the deadline but still inside the default claim window
+    env.ledger().with_mut(|li| {
+        li.timestamp = 10000 + f.client.get_claim_window() - 1;
+    });
+
+    let res = f.client.try_reclaim_expired(&quest_id);
+    assert_eq!(res, Err(Ok(Error::QuestStillActive)));
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 500);
+}
+
+#[test]
+fn test_approved_reward_claimable_within_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("EQ2");
+    let f = setup_quest(&env, &quest_id);
+    fund(&f, &quest_id);
+
+    let submitter = Address::generate(&env);
+    submit_and_approve(&env, &f, &quest_id, &submitter);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 10001;
+    });
+
+    f.client.claim_reward(&quest_id, &submitter);
+    assert_eq!(f.token_client.balance(&submitter), 100);
+}
+
+#[test]
+fn test_reclaim_expired_refunds_and_voids_unclaimed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("EQ3");
+    let f = setup_quest(&env, &quest_id);
+    fund(&f, &quest_id);
+
+    let claimed = Address::generate(&env);
+    let unclaimed = Address::generate(&env);
+    submit_and_approve(&env, &f, &quest_id, &claimed);
+    submit_and_approve(&env, &f, &quest_id, &unclaimed);
+    f.client.claim_reward(&quest_id, &claimed);
+
+    let admin = Address::generate(&env);
+    f.client.initialize(&admin);
+    f.client.set_claim_window(&admin, &100);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 10100;
+    });
+
+    // Anyone can trigger the sweep; funds only go back to the creator
+    let refunded = f.client.reclaim_expired(&quest_id);
+    assert_eq!(refunded, 400);
+    assert_eq!(f.token_client.balance(&f.creator), 400);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 0);
+
+    // The approved-but-unclaimed reward is void
+    let res = f.client.try_claim_reward(&quest_id, &unclaimed);
+    assert_eq!(res, Err(Ok(Error::QuestExpired)));
+
+    // A second sweep is an invalid Expired -> Expired transition
+    let res = f.client.try_reclaim_expired(&quest_id);
+    assert_eq!(res, Err(Ok(Error::InvalidStatusTransition)));
+}
+
+#[test]
+fn test_reclaim_expired_sweeps_unclaimed_after_cancel() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("EQ4");
+    let f = setup_quest(&env, &quest_id);
+    fund(&f, &quest_id);
+
+    let unclaimed = Address::generate(&env);
+    submit_and_approve(&env, &f, &quest_id, &unclaimed);
+    assert_eq!(f.client.cancel_quest(&quest_id, &f.creator), 400);
+
+    // The reserved reward stays claimable until the window closes
+    env.ledger().with_mut(|li| {
+        li.timestamp = 10000 + f.client.get_claim_window() - 1;
+    });
+    let res = f.client.try_reclaim_expired(&quest_id);
+    assert_eq!(res, Err(Ok(Error::QuestStillActive)));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 10000 + f.client.get_claim_window();
+    });
+    assert_eq!(f.client.reclaim_expired(&quest_id), 100);
+    assert_eq!(f.token_client.balance(&f.creator), 500);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 0);
+
+    let res = f.client.try_claim_reward(&quest_id, &unclaimed);
+    assert_eq!(res, Err(Ok(Error::QuestExpired)));
+}
+
+#[test]
+fn test_set_claim_window_non_admin_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("EQ5");
+    let f = setup_quest(&env, &quest_id);
+
+    let res = f.client.try_set_claim_window(&f.creator, &0);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+}
+
+//================================================================================
+// Top-up and Reward Increase Tests
+//================================================================================
+
+#[test]
+fn test_top_up_quest_adds_to_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TQ1");
+    let f = setup_quest(&env, &quest_id);
+    fund(&f, &quest_id);
+
+    f.token_admin.mint(&f.creator, &250);
+    let escrow = f.client.top_up_quest(&quest_id, &f.creator, &250);
+
+    assert_eq!(escrow, 750);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 750);
+    assert_eq!(f.token_client.balance(&f.contract_id), 750);
+
+    let events = env.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    let t_name: Symbol = topics.get(0).unwrap().into_val(&env);
+    assert_eq!(t_name, symbol_short!("q_topup"));
+    let (asset, amount, escrow_after): (Address, i128, i128) = data.into_val(&env);
+    assert_eq!(asset, f.token);
+    assert_eq!(amount, 250);
+    assert_eq!(escrow_after, 750);
+}
+
+#[test]
+fn test_top_up_quest_invalid_amount_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TQ2");
+    let f = setup_quest(&env, &quest_id);
+
+    let res = f.client.try_top_up_quest(&quest_id, &f.creator, &0);
+    assert_eq!(res, Err(Ok(Error::InvalidRewardAmount)));
+}
+
+#[test]
+fn test_top_up_cancelled_quest_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TQ3");
+    let f = setup_quest(&env, &quest_id);
+    f.client.cancel_quest(&quest_id, &f.creator);
+
+    f.token_admin.mint(&f.creator, &100);
+    let res = f.client.try_top_up_quest(&quest_id, &f.creator, &100);
+    assert_eq!(res, Err(Ok(Error::QuestNotActive)));
+}
+
+#[test]
+fn test_increase_reward_escrows_difference_for_unpaid_claims() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TQ4");
+    let f = setup_quest(&env, &quest_id);
+    fund(&f, &quest_id);
+
+    // One claim already paid at the old reward
+    let early = Address::generate(&env);
+    submit_and_approve(&env, &f, &quest_id, &early);
+    f.client.claim_reward(&quest_id, &early);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 400);
+
+    // One approved but unpaid claim benefits from the raise
+    let approved = Address::generate(&env);
+    submit_and_approve(&env, &f, &quest_id, &approved);
+
+    // 4 unpaid claim slots * (150 - 100)
+    f.token_admin.mint(&f.creator, &200);
+    let deposited = f.client.increase_reward(&quest_id, &f.creator, &150);
+    assert_eq!(deposited, 200);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 600);
+
+    f.client.claim_reward(&quest_id, &approved);
+    assert_eq!(f.token_client.balance(&approved), 150);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 450);
+
+    let events = env.events().all();
+    let reward_event = events
+        .iter()
+        .find(|(_, topics, _)| {
+            let name: Symbol = topics.get(0).unwrap().into_val(&env);
+            name == symbol_short!("rwd_inc")
+        })
+        .unwrap();
+    let (old_amount, new_amount, deposited): (i128, i128, i128) = reward_event.2.into_val(&env);
+    assert_eq!((old_amount, new_amount, deposited), (100, 150, 200));
+}
+
+#[test]
+fn test_increase_reward_unfunded_quest_defers_deposit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TQ5");
+    let f = setup_quest(&env, &quest_id);
+
+    let deposited = f.client.increase_reward(&quest_id, &f.creator, &200);
+    assert_eq!(deposited, 0);
+
+    // Funding now escrows the raised reward for every claim
+    f.token_admin.mint(&f.creator, &1000);
+    assert_eq!(f.client.fund_quest(&quest_id, &f.creator), 1000);
+}
+
+#[test]
+fn test_increase_reward_cannot_lower_reward() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TQ6");
+    let f = setup_quest(&env, &quest_id);
+
+    let res = f.client.try_increase_reward(&quest_id, &f.creator, &100);
+    assert_eq!(res, Err(Ok(Error::InvalidRewardAmount)));
+
+    let res = f.client.try_increase_reward(&quest_id, &f.creator, &50);
+    assert_eq!(res, Err(Ok(Error::InvalidRewardAmount)));
+}
+
+#[test]
+fn test_increase_reward_by_non_creator_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TQ7");
+    let f = setup_quest(&env, &quest_id);
+
+    let res = f.client.try_increase_reward(&quest_id, &f.verifier, &200);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+}
+
+//================================================================================
+// Amendment Tests
+//================================================================================
+
+/// Collect `q_amend` events as (field, data) pairs.
+fn amended_events(env: &Env) -> soroban_sdk::Vec<(Symbol, soroban_sdk::Val)> {
+    let mut out = soroban_sdk::Vec::new(env);
+    for (_, topics, data) in env.events().all().iter() {
+        let name: Symbol = topics.get(0).unwrap().into_val(env);
+        if name == symbol_short!("q_amend") {
+            let field: Symbol = topics.get(2).unwrap().into_val(env);
+            out.push_back((field, data));
+        }
+    }
+    out
+}
+
+#[test]
+fn test_amend_quest_extends_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("AQ1");
+    let f = setup_quest(&env, &quest_id);
+
+    f.client
+        .amend_quest(&quest_id, &f.creator, &Some(20000), &None, &None);
+
+    let events = amended_events(&env);
+    assert_eq!(events.len(), 1);
+    let (field, data) = events.get(0).unwrap();
+    assert_eq!(field, symbol_short!("deadline"));
+    let (old, new): (u64, u64) = data.into_val(&env);
+    assert_eq!((old, new), (10000, 20000));
+
+    // Submissions are accepted past the original deadline
+    env.ledger().with_mut(|li| {
+        li.timestamp = 15000;
+    });
+    let submitter = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    f.client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
+}
+
+#[test]
+fn test_amend_quest_shortened_deadline_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("AQ2");
+    let f = setup_quest(&env, &quest_id);
+
+    let res = f
+        .client
+        .try_amend_quest(&quest_id, &f.creator, &Some(9000), &None, &None);
+    assert_eq!(res, Err(Ok(Error::DeadlineNotExtended)));
+}
+
+#[test]
+fn test_amend_quest_past_deadline_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("AQ3");
+    let f = setup_quest(&env, &quest_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 30000;
+    });
+
+    let res = f
+        .client
+        .try_amend_quest(&quest_id, &f.creator, &Some(20000), &None, &None);
+    assert_eq!(res, Err(Ok(Error::DeadlineInPast)));
+}
+
+#[test]
+fn test_amend_quest_changes_verifier() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("AQ4");
+    let f = setup_quest(&env, &quest_id);
+    let new_verifier = Address::generate(&env);
+
+    f.client
+        .amend_quest(&quest_id, &f.creator, &None, &Some(new_verifier.clone()), &None);
+
+    let events = amended_events(&env);
+    let (field, data) = events.get(0).unwrap();
+    assert_eq!(field, symbol_short!("verifier"));
+    let (old, new): (Address, Address) = data.into_val(&env);
+    assert_eq!(old, f.verifier);
+    assert_eq!(new, new_verifier);
+
+    // Only the new verifier can approve
+    let submitter = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    f.client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
+    let res = f
+        .client
+        .try_approve_submission(&quest_id, &submitter, &f.verifier);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+    f.client
+        .approve_submission(&quest_id, &submitter, &new_verifier);
+}
+
+#[test]
+fn test_amend_quest_verifier_same_as_creator_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("AQ5");
+    let f = setup_quest(&env, &quest_id);
+
+    let res = f.client.try_amend_quest(
+        &quest_id,
+        &f.creator,
+        &None,
+        &Some(f.creator.clone()),
+        &None,
+    );
+    assert_eq!(res, Err(Ok(Error::InvalidAddress)));
+}
+
+#[test]
+fn test_amend_quest_raises_cap_and_escrows_extra_budget() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("AQ6");
+    let f = setup_quest(&env, &quest_id);
+    fund(&f, &quest_id);
+
+    f.token_admin.mint(&f.creator, &300);
+    f.client
+        .amend_quest(&quest_id, &f.creator, &None, &None, &Some(8));
+
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 800);
+    assert_eq!(f.token_client.balance(&f.creator), 0);
+
+    let events = amended_events(&env);
+    let (field, data) = events.get(0).unwrap();
+    assert_eq!(field, symbol_short!("max_claim"));
+    let (old, new): (u32, u32) = data.into_val(&env);
+    assert_eq!((old, new), (5, 8));
+}
+
+#[test]
+fn test_amend_quest_lowering_cap_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("AQ7");
+    let f = setup_quest(&env, &quest_id);
+
+    let res = f
+        .client
+        .try_amend_quest(&quest_id, &f.creator, &None, &None, &Some(3));
+    assert_eq!(res, Err(Ok(Error::InvalidMaxClaims)));
+}
+
+#[test]
+fn test_amend_quest_by_non_creator_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("AQ8");
+    let f = setup_quest(&env, &quest_id);
+
+    let res = f
+        .client
+        .try_amend_quest(&quest_id, &f.verifier, &Some(20000), &None, &None);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+}
+
+//================================================================================
+// Sponsorship Tests
+//================================================================================
+
+fn sponsor(f: &QuestFixture, quest_id: &Symbol, sponsor: &Address, amount: i128) {
+    f.token_admin.mint(sponsor, &amount);
+    f.client.sponsor_quest(quest_id, sponsor, &amount);
+}
+
+#[test]
+fn test_sponsor_quest_records_contributions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("SP1");
+    let f = setup_quest(&env, &quest_id);
+    let dao = Address::generate(&env);
+
+    // Sponsoring before funding lowers what the creator has to escrow
+    sponsor(&f, &quest_id, &dao, 200);
+    f.token_admin.mint(&f.creator, &500);
+    assert_eq!(f.client.fund_quest(&quest_id, &f.creator), 300);
+    assert_eq!(f.token_client.balance(&f.creator), 200);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 500);
+
+    // Repeat contributions are merged per sponsor
+    sponsor(&f, &quest_id, &dao, 100);
+
+    let sponsors = f.client.get_sponsors(&quest_id);
+    assert_eq!(sponsors.len(), 2);
+    assert_eq!(sponsors.get(0).unwrap(), Sponsorship { sponsor: dao, amount: 300 });
+    assert_eq!(sponsors.get(1).unwrap(), Sponsorship { sponsor: f.creator.clone(), amount: 300 });
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 600);
+}
+
+#[test]
+fn test_cancel_refunds_sponsors_pro_rata() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("SP2");
+    let f = setup_quest(&env, &quest_id);
+    let dao = Address::generate(&env);
+
+    sponsor(&f, &quest_id, &dao, 200);
+    f.token_admin.mint(&f.creator, &300);
+    f.client.fund_quest(&quest_id, &f.creator);
+
+    let hunter = Address::generate(&env);
+    submit_and_approve(&env, &f, &quest_id, &hunter);
+    f.client.claim_reward(&quest_id, &hunter);
+
+    // 400 left, split 2:3 between the DAO and the creator
+    assert_eq!(f.client.cancel_quest(&quest_id, &f.creator), 400);
+    assert_eq!(f.token_client.balance(&dao), 160);
+    assert_eq!(f.token_client.balance(&f.creator), 240);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 0);
+}
+
+#[test]
+fn test_reclaim_expired_refunds_sponsors_without_dust() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("SP3");
+    let f = setup_quest(&env, &quest_id);
+    let dao_a = Address::generate(&env);
+    let dao_b = Address::generate(&env);
+
+    sponsor(&f, &quest_id, &dao_a, 133);
+    sponsor(&f, &quest_id, &dao_b, 133);
+    f.token_admin.mint(&f.creator, &234);
+    f.client.fund_quest(&quest_id, &f.creator);
+
+    let hunter = Address::generate(&env);
+    submit_and_approve(&env, &f, &quest_id, &hunter);
+    f.client.claim_reward(&quest_id, &hunter);
+
+    let admin = Address::generate(&env);
+    f.client.initialize(&admin);
+    f.client.set_claim_window(&admin, &0);
+    env.ledger().with_mut(|li| {
+        li.timestamp = 10001;
+    });
+
+    // 400 split 133:133:234 rounds down to 106 each; the last sponsor absorbs the dust
+    assert_eq!(f.client.reclaim_expired(&quest_id), 400);
+    assert_eq!(f.token_client.balance(&dao_a), 106);
+    assert_eq!(f.token_client.balance(&dao_b), 106);
+    assert_eq!(f.token_client.balance(&f.creator), 188);
+    assert_eq!(f.token_client.balance(&f.contract_id), 0);
+}
+
+#[test]
+fn test_full_sponsor_list_never_blocks_creator() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("SP5");
+    let f = setup_quest(&env, &quest_id);
+
+    // Sponsorships must fund at least one claim
+    let dao = Address::generate(&env);
+    f.token_admin.mint(&dao, &99);
+    let res = f.client.try_sponsor_quest(&quest_id, &dao, &99);
+    assert_eq!(res, Err(Ok(Error::InvalidRewardAmount)));
+
+    for _ in 0..earn_quest::validation::MAX_SPONSORS {
+        sponsor(&f, &quest_id, &Address::generate(&env), 100);
+    }
+    f.token_admin.mint(&dao, &1);
+    let res = f.client.try_sponsor_quest(&quest_id, &dao, &100);
+    assert_eq!(res, Err(Ok(Error::ArrayTooLong)));
+
+    sponsor(&f, &quest_id, &f.creator, 100);
+    let sponsors = f.client.get_sponsors(&quest_id);
+    assert_eq!(sponsors.len(), earn_quest::validation::MAX_SPONSORS + 1);
+    assert_eq!(
+        sponsors.last().unwrap(),
+        Sponsorship { sponsor: f.creator.clone(), amount: 100 }
+    );
+}
+
+#[test]
+fn test_sponsor_cancelled_quest_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("SP4");
+    let f = setup_quest(&env, &quest_id);
+    fund(&f, &quest_id);
+    f.client.cancel_quest(&quest_id, &f.creator);
+
+    let dao = Address::generate(&env);
+    f.token_admin.mint(&dao, &100);
+    let res = f.client.try_sponsor_quest(&quest_id, &dao, &100);
+    assert_eq!(res, Err(Ok(Error::QuestNotActive)));
+}
+
+//================================================================================
+// Bounty Tests
+//================================================================================
+
+/// Register and fund a single-claim bounty alongside the fixture quest, with two entries.
+fn setup_bounty(env: &Env, f: &QuestFixture, bounty_id: &Symbol) -> (Address, Address) {
+    f.client.register_quest(bounty_id, &f.creator, &f.token, &100, &f.verifier, &10000, &1, &None);
+    f.client.set_bounty_mode(bounty_id, &f.creator);
+    f.token_admin.mint(&f.creator, &100);
+    f.client.fund_quest(bounty_id, &f.creator);
+
+    let proof = BytesN::from_array(env, &[1u8; 32]);
+    let alice = Address::generate(env);
+    let bob = Address::generate(env);
+    f.client.submit_proof(bounty_id, &alice, &0, &proof, &Vec::new(env));
+    f.client.submit_proof(bounty_id, &bob, &0, &proof, &Vec::new(env));
+    (alice, bob)
+}
+
+#[test]
+fn test_select_winner_pays_only_the_winner() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("Q1");
+    let bounty_id = symbol_short!("BNT1");
+    let f = setup_quest(&env, &quest_id);
+    let (alice, bob) = setup_bounty(&env, &f, &bounty_id);
+    assert_eq!(f.client.get_submitters(&bounty_id), soroban_sdk::vec![&env, alice.clone(), bob.clone()]);
+
+    // Only bounty entrants are indexed; other quests just count submitters
+    fund(&f, &quest_id);
+    f.client.submit_proof(&quest_id, &alice, &0, &BytesN::from_array(&env, &[1u8; 32]), &Vec::new(&env));
+    assert!(f.client.get_submitters(&quest_id).is_empty());
+    let res = f.client.try_set_reputation_gate(&quest_id, &f.creator, &1, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::QuestTermsLocked)));
+
+    // The other pending entry is rejected in the same call
+    assert_eq!(f.client.select_winner(&bounty_id, &bob, &f.verifier), 1);
+
+    f.client.claim_reward(&bounty_id, &bob);
+    assert_eq!(f.token_client.balance(&bob), 100);
+
+    let res = f.client.try_claim_reward(&bounty_id, &alice);
+    assert_eq!(res, Err(Ok(Error::InvalidStatusTransition)));
+
+    // The bounty is completed and takes no further entries
+    let proof = BytesN::from_array(&env, &[2u8; 32]);
+    let late = Address::generate(&env);
+    let res = f.client.try_submit_proof(&bounty_id, &late, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::QuestNotActive)));
+}
+
+#[test]
+fn test_bounty_requires_select_winner() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("Q1");
+    let bounty_id = symbol_short!("BNT2");
+    let f = setup_quest(&env, &quest_id);
+    let (alice, bob) = setup_bounty(&env, &f, &bounty_id);
+
+    let res = f.client.try_approve_submission(&bounty_id, &alice, &f.verifier);
+    assert_eq!(res, Err(Ok(Error::WrongQuestKind)));
+
+    // A winner can only be picked once
+    f.client.select_winner(&bounty_id, &alice, &f.verifier);
+    let res = f.client.try_select_winner(&bounty_id, &bob, &f.verifier);
+    assert_eq!(res, Err(Ok(Error::InvalidStatusTransition)));
+}
+
+#[test]
+fn test_select_winner_by_non_verifier_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("Q1");
+    let bounty_id = symbol_short!("BNT3");
+    let f = setup_quest(&env, &quest_id);
+    let (alice, _) = setup_bounty(&env, &f, &bounty_id);
+
+    let res = f.client.try_select_winner(&bounty_id, &alice, &f.creator);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_bounty_mode_requires_single_claim_quest() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("Q1");
+    let f = setup_quest(&env, &quest_id);
+
+    // The fixture quest pays up to 5 claims
+    let res = f.client.try_set_bounty_mode(&quest_id, &f.creator);
+    assert_eq!(res, Err(Ok(Error::InvalidMaxClaims)));
+
+    let submitter = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    f.client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
+    let res = f.client.try_select_winner(&quest_id, &submitter, &f.verifier);
+    assert_eq!(res, Err(Ok(Error::WrongQuestKind)));
+}
+
+//================================================================================
+// Contest Tests
+//================================================================================
+
+/// Register and fund a contest paying 500/300/100 alongside the fixture quest,
+/// with three approved entries.
+fn setup_contest(env: &Env, f: &QuestFixture, contest_id: &Symbol) -> [Address; 3] {
+    f.client.register_quest(contest_id, &f.creator, &f.token, &100, &f.verifier, &10000, &1, &None);
+    f.client.set_prize_table(contest_id, &f.creator, &soroban_sdk::vec![env, 500, 300, 100]);
+    f.token_admin.mint(&f.creator, &900);
+    assert_eq!(f.client.fund_quest(contest_id, &f.creator), 900);
+
+    let entrants = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    for entrant in entrants.iter() {
+        submit_and_approve(env, f, contest_id, entrant);
+    }
+    entrants
+}
+
+#[test]
+fn test_contest_pays_prize_by_rank_and_refunds_unassigned() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("Q1");
+    let contest_id = symbol_short!("CON1");
+    let f = setup_quest(&env, &quest_id);
+    let [alice, bob, carol] = setup_contest(&env, &f, &contest_id);
+
+    // Only two winners: the third prize goes back to the creator
+    let ranked = soroban_sdk::vec![&env, bob.clone(), alice.clone()];
+    assert_eq!(f.client.finalize_ranking(&contest_id, &f.verifier, &ranked), 100);
+    assert_eq!(f.token_client.balance(&f.creator), 100);
+
+    f.client.claim_reward(&contest_id, &alice);
+    f.client.claim_reward(&contest_id, &bob);
+    assert_eq!(f.token_client.balance(&bob), 500);
+    assert_eq!(f.token_client.balance(&alice), 300);
+    assert_eq!(f.client.get_quest_escrow(&contest_id), 0);
+
+    let res = f.client.try_claim_reward(&contest_id, &carol);
+    assert_eq!(res, Err(Ok(Error::NotRanked)));
+}
+
+#[test]
+fn test_reclaim_unclaimed_prize_of_completed_contest() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("Q1");
+    let contest_id = symbol_short!("CON4");
+    let f = setup_quest(&env, &quest_id);
+    let [alice, bob, _] = setup_contest(&env, &f, &contest_id);
+
+    let ranked = soroban_sdk::vec![&env, bob.clone(), alice.clone()];
+    f.client.finalize_ranking(&contest_id, &f.verifier, &ranked);
+    f.client.claim_reward(&contest_id, &bob);
+
+    // Alice never claims her prize; it returns to the creator after the window
+    env.ledger().with_mut(|li| {
+        li.timestamp = 10000 + f.client.get_claim_window();
+    });
+    assert_eq!(f.client.reclaim_expired(&contest_id), 300);
+    assert_eq!(f.token_client.balance(&f.creator), 400);
+
+    let res = f.client.try_claim_reward(&contest_id, &alice);
+    assert_eq!(res, Err(Ok(Error::QuestExpired)));
+}
+
+#[test]
+fn test_contest_claim_before_ranking_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("Q1");
+    let contest_id = symbol_short!("CON2");
+    let f = setup_quest(&env, &quest_id);
+    let [alice, _, _] = setup_contest(&env, &f, &contest_id);
+
+    let res = f.client.try_claim_reward(&contest_id, &alice);
+    assert_eq!(res, Err(Ok(Error::NotRanked)));
+}
+
+#[test]
+fn test_finalize_ranking_validates_winners() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("Q1");
+    let contest_id = symbol_short!("CON3");
+    let f = setup_quest(&env, &quest_id);
+    let [alice, bob, carol] = setup_contest(&env, &f, &contest_id);
+
+    let twice = soroban_sdk::vec![&env, alice.clone(), alice.clone()];
+    let res = f.client.try_finalize_ranking(&contest_id, &f.verifier, &twice);
+    assert_eq!(res, Err(Ok(Error::DuplicateWinner)));
+
+    let proof = BytesN::from_array(&env, &[2u8; 32]);
+    let pending = Address::generate(&env);
+    f.client.submit_proof(&contest_id, &pending, &0, &proof, &Vec::new(&env));
+    let res = f.client.try_finalize_ranking(&contest_id, &f.verifier, &soroban_sdk::vec![&env, pending.clone()]);
+    assert_eq!(res, Err(Ok(Error::InvalidSubmissionStatus)));
+
+    let too_many = soroban_sdk::vec![&env, alice, bob, carol, pending];
+    let res = f.client.try_finalize_ranking(&contest_id, &f.verifier, &too_many);
+    assert_eq!(res, Err(Ok(Error::ArrayTooLong)));
+}
+
+#[test]
+fn test_prize_table_locked_after_funding() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("Q1");
+    let f = setup_quest(&env, &quest_id);
+    fund(&f, &quest_id);
+
+    let res = f.client.try_set_prize_table(&quest_id, &f.creator, &soroban_sdk::vec![&env, 300, 200]);
+    assert_eq!(res, Err(Ok(Error::AlreadyFunded)));
+
+    let res = f.client.try_finalize_ranking(&quest_id, &f.verifier, &soroban_sdk::vec![&env]);
+    assert_eq!(res, Err(Ok(Error::WrongQuestKind)));
+}
+
+//================================================================================
+// Early-Bird Tier Tests
+//================================================================================
+
+/// Pay the first 2 approvals 100 and the next 3 approvals 50, then fund.
+fn set_tiers_and_fund(env: &Env, f: &QuestFixture, quest_id: &Symbol) {
+    let tiers = soroban_sdk::vec![
+        env,
+        RewardTier { count: 2, amount: 100 },
+        RewardTier { count: 3, amount: 50 },
+    ];
+    f.client.set_reward_tiers(quest_id, &f.creator, &tiers);
+    f.token_admin.mint(&f.creator, &350);
+    assert_eq!(f.client.fund_quest(quest_id, &f.creator), 350);
+}
+
+#[test]
+fn test_tiered_rewards_follow_approval_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TIER1");
+    let f = setup_quest(&env, &quest_id);
+    set_tiers_and_fund(&env, &f, &quest_id);
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+    submit_and_approve(&env, &f, &quest_id, &first);
+    submit_and_approve(&env, &f, &quest_id, &second);
+    submit_and_approve(&env, &f, &quest_id, &third);
+
+    // Claim order does not matter; the tier was fixed at approval
+    f.client.claim_reward(&quest_id, &third);
+    f.client.claim_reward(&quest_id, &first);
+    f.client.claim_reward(&quest_id, &second);
+    assert_eq!(f.token_client.balance(&first), 100);
+    assert_eq!(f.token_client.balance(&second), 100);
+    assert_eq!(f.token_client.balance(&third), 50);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 100);
+}
+
+#[test]
+fn test_approval_beyond_last_tier_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TIER2");
+    let f = setup_quest(&env, &quest_id);
+    let tiers = soroban_sdk::vec![&env, RewardTier { count: 1, amount: 100 }];
+    f.client.set_reward_tiers(&quest_id, &f.creator, &tiers);
+
+    submit_and_approve(&env, &f, &quest_id, &Address::generate(&env));
+
+    let late = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    f.client.submit_proof(&quest_id, &late, &0, &proof, &Vec::new(&env));
+    let res = f.client.try_approve_submission(&quest_id, &late, &f.verifier);
+    assert_eq!(res, Err(Ok(Error::QuestFull)));
+}
+
+#[test]
+fn test_cancel_tiered_quest_keeps_approved_tier_rewards() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TIER3");
+    let f = setup_quest(&env, &quest_id);
+    set_tiers_and_fund(&env, &f, &quest_id);
+
+    let submitter = Address::generate(&env);
+    submit_and_approve(&env, &f, &quest_id, &submitter);
+
+    assert_eq!(f.client.cancel_quest(&quest_id, &f.creator), 250);
+
+    f.client.claim_reward(&quest_id, &submitter);
+    assert_eq!(f.token_client.balance(&submitter), 100);
+    assert_eq!(f.token_client.balance(&f.contract_id), 0);
+}
+
+#[test]
+fn test_tiers_locked_after_funding() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TIER4");
+    let f = setup_quest(&env, &quest_id);
+    set_tiers_and_fund(&env, &f, &quest_id);
+
+    let tiers = soroban_sdk::vec![&env, RewardTier { count: 5, amount: 70 }];
+    let res = f.client.try_set_reward_tiers(&quest_id, &f.creator, &tiers);
+    assert_eq!(res, Err(Ok(Error::AlreadyFunded)));
+
+    let res = f.client.try_increase_reward(&quest_id, &f.creator, &200);
+    assert_eq!(res, Err(Ok(Error::QuestTermsLocked)));
+}
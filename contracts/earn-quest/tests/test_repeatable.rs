@@ -0,0 +1,165 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, Symbol, Vec,
+};
+
+extern crate earn_quest;
+use earn_quest::errors::Error;
+use earn_quest::types::{RepeatPolicy, SubmissionStatus, VestingSchedule};
+
+mod common;
+use common::{setup_funded_quest, FundedQuest};
+
+//================================================================================
+// Test Setup
+//================================================================================
+
+const COOLDOWN: u64 = 3600;
+
+/// Make the quest completable twice per user, an hour apart.
+fn make_repeatable(f: &FundedQuest, quest_id: &Symbol) {
+    let policy = RepeatPolicy {
+        cooldown_seconds: COOLDOWN,
+        max_completions: 2,
+    };
+    f.client.set_repeat_policy(quest_id, &f.creator, &policy);
+    assert_eq!(f.client.get_repeat_policy(quest_id), Some(policy));
+}
+
+fn complete(f: &FundedQuest, quest_id: &Symbol, user: &Address, proof: &BytesN<32>) {
+    f.client.submit_proof(quest_id, user, &0, proof, &Vec::new(&f.client.env));
+    f.client.approve_submission(quest_id, user, &f.verifier);
+    f.client.claim_reward(quest_id, user);
+}
+
+//================================================================================
+// Repeatable Quest Tests
+//================================================================================
+
+#[test]
+fn test_repeat_after_cooldown_pays_again() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("RP1");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 3);
+    make_repeatable(&f, &quest_id);
+    let user = Address::generate(&env);
+
+    complete(&f, &quest_id, &user, &BytesN::from_array(&env, &[1u8; 32]));
+    env.ledger().with_mut(|li| li.timestamp += COOLDOWN);
+    complete(&f, &quest_id, &user, &BytesN::from_array(&env, &[2u8; 32]));
+
+    assert_eq!(f.token_client.balance(&user), 2000);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 1000);
+
+    // The first attempt is archived, the second one is current
+    let first = f.client.get_submission_attempt(&quest_id, &user, &1);
+    assert_eq!(first.status, SubmissionStatus::Paid);
+    assert_eq!(first.proof_hash, BytesN::from_array(&env, &[1u8; 32]));
+    let second = f.client.get_submission_attempt(&quest_id, &user, &2);
+    assert_eq!(second.status, SubmissionStatus::Paid);
+    assert_eq!(second.attempt, 2);
+
+    let res = f.client.try_get_submission_attempt(&quest_id, &user, &3);
+    assert_eq!(res, Err(Ok(Error::SubmissionNotFound)));
+}
+
+#[test]
+fn test_repeat_before_cooldown_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("RP2");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 3);
+    make_repeatable(&f, &quest_id);
+    let user = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+
+    complete(&f, &quest_id, &user, &proof);
+    env.ledger().with_mut(|li| li.timestamp += COOLDOWN - 1);
+
+    let res = f.client.try_submit_proof(&quest_id, &user, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::TimelockNotExpired)));
+}
+
+#[test]
+fn test_completion_cap_per_user() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("RP3");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 3);
+    make_repeatable(&f, &quest_id);
+    let user = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+
+    complete(&f, &quest_id, &user, &proof);
+    env.ledger().with_mut(|li| li.timestamp += COOLDOWN);
+    complete(&f, &quest_id, &user, &proof);
+    env.ledger().with_mut(|li| li.timestamp += COOLDOWN);
+
+    let res = f.client.try_submit_proof(&quest_id, &user, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::AlreadyClaimed)));
+
+    // Other users still have their own completions
+    complete(&f, &quest_id, &Address::generate(&env), &proof);
+}
+
+#[test]
+fn test_single_completion_quest_not_repeatable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("RP4");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 3);
+    let user = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+
+    complete(&f, &quest_id, &user, &proof);
+    env.ledger().with_mut(|li| li.timestamp += COOLDOWN);
+
+    let res = f.client.try_submit_proof(&quest_id, &user, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::InvalidStatusTransition)));
+}
+
+#[test]
+fn test_repeat_policy_locked_terms() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("RP5");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 3);
+    let policy = RepeatPolicy {
+        cooldown_seconds: COOLDOWN,
+        max_completions: 2,
+    };
+
+    // Vesting positions are per quest and user, so they cannot repeat
+    let schedule = VestingSchedule {
+        cliff_seconds: 0,
+        duration_seconds: 100,
+        tranches: 1,
+    };
+    f.client.set_vesting(&quest_id, &f.creator, &schedule);
+    let res = f.client.try_set_repeat_policy(&quest_id, &f.creator, &policy);
+    assert_eq!(res, Err(Ok(Error::QuestTermsLocked)));
+    f.client.remove_vesting(&quest_id, &f.creator);
+
+    let zero = RepeatPolicy {
+        cooldown_seconds: COOLDOWN,
+        max_completions: 0,
+    };
+    let res = f.client.try_set_repeat_policy(&quest_id, &f.creator, &zero);
+    assert_eq!(res, Err(Ok(Error::InvalidMaxClaims)));
+
+    // Terms are fixed once a submission has been approved
+    let user = Address::generate(&env);
+    f.client.submit_proof(&quest_id, &user, &0, &BytesN::from_array(&env, &[1u8; 32]), &Vec::new(&env));
+    f.client.approve_submission(&quest_id, &user, &f.verifier);
+    let res = f.client.try_set_repeat_policy(&quest_id, &f.creator, &policy);
+    assert_eq!(res, Err(Ok(Error::QuestTermsLocked)));
+}
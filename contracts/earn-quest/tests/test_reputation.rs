@@ -1,12 +1,16 @@
 #![cfg(test)]
 
 use soroban_sdk::token::{StellarAssetClient, TokenClient};
-use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Env, Symbol};
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, BytesN, Env, Symbol, Vec};
 
 extern crate earn_quest;
+use earn_quest::errors::Error;
 use earn_quest::types::Badge;
 use earn_quest::{EarnQuestContract, EarnQuestContractClient};
 
+mod common;
+use common::allow_asset;
+
 fn setup_contract_and_token(
     env: &Env,
 ) -> (
@@ -21,10 +25,8 @@ fn setup_contract_and_token(
     let admin = Address::generate(env);
     let token_contract_obj = env.register_stellar_asset_contract_v2(admin.clone());
     let token_contract = token_contract_obj.address();
-    let token_admin_client = StellarAssetClient::new(env, &token_contract);
     let token_client = TokenClient::new(env, &token_contract);
-
-    token_admin_client.mint(&contract_id, &10000);
+    allow_asset(env, &client, &token_contract);
 
     (contract_id, client, token_contract, token_client)
 }
@@ -47,10 +49,16 @@ fn complete_quest(
         &reward_amount,
         verifier,
         &10000,
+        &1,
+        &None,
     );
 
+    // Escrow the single reward from the creator
+    StellarAssetClient::new(env, token_contract).mint(creator, &reward_amount);
+    client.fund_quest(&quest_id, creator);
+
     let proof = BytesN::from_array(env, &[1u8; 32]);
-    client.submit_proof(&quest_id, submitter, &proof);
+    client.submit_proof(&quest_id, submitter, &0, &proof, &Vec::new(env));
     client.approve_submission(&quest_id, submitter, verifier);
     client.claim_reward(&quest_id, submitter);
 }
@@ -92,6 +100,8 @@ fn test_xp_awarded_on_quest_completion() {
 fn test_level_calculation_progression() {
     let env = Env::default();
     env.mock_all_auths();
+    // Several funded quests exceed the default cumulative test budget
+    env.budget().reset_unlimited();
 
     let (_, client, token_contract, _) = setup_contract_and_token(&env);
 
@@ -431,6 +441,8 @@ fn test_multiple_users_independent_stats() {
 fn test_max_level_cap() {
     let env = Env::default();
     env.mock_all_auths();
+    // Twenty funded quests exceed the default cumulative test budget
+    env.budget().reset_unlimited();
 
     let (_, client, token_contract, _) = setup_contract_and_token(&env);
 
@@ -455,3 +467,106 @@ fn test_max_level_cap() {
     assert_eq!(stats.level, 5);
     assert_eq!(stats.xp, 2000);
 }
+
+//================================================================================
+// Reputation Gate Tests
+//================================================================================
+
+/// Register and fund a single-claim quest paying 100.
+fn register_funded(
+    client: &EarnQuestContractClient,
+    env: &Env,
+    quest_id: &Symbol,
+    creator: &Address,
+    token_contract: &Address,
+    verifier: &Address,
+) {
+    client.register_quest(quest_id, creator, token_contract, &100, verifier, &10000, &1, &None);
+    StellarAssetClient::new(env, token_contract).mint(creator, &100);
+    client.fund_quest(quest_id, creator);
+}
+
+#[test]
+fn test_level_gated_quest() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, token_contract, _) = setup_contract_and_token(&env);
+
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+
+    let gated = symbol_short!("GATED");
+    register_funded(&client, &env, &gated, &creator, &token_contract, &verifier);
+    client.set_reputation_gate(&gated, &creator, &2, &vec![&env]);
+
+    let res = client.try_submit_proof(&gated, &submitter, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::PrerequisiteNotMet)));
+
+    // Three completions reach level 2
+    for i in 1..=3 {
+        complete_quest(
+            &client,
+            &env,
+            Symbol::new(&env, &format!("Q{}", i)),
+            &creator,
+            &token_contract,
+            &verifier,
+            &submitter,
+            100,
+        );
+    }
+    client.submit_proof(&gated, &submitter, &0, &proof, &Vec::new(&env));
+}
+
+#[test]
+fn test_badge_gated_quest() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, token_contract, _) = setup_contract_and_token(&env);
+
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+
+    client.initialize(&admin);
+    let gated = symbol_short!("GATED");
+    register_funded(&client, &env, &gated, &creator, &token_contract, &verifier);
+    client.set_reputation_gate(&gated, &creator, &0, &vec![&env, Badge::Explorer, Badge::Veteran]);
+
+    // Every required badge is needed
+    client.grant_badge(&admin, &submitter, &Badge::Explorer);
+    let res = client.try_submit_proof(&gated, &submitter, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::PrerequisiteNotMet)));
+
+    client.grant_badge(&admin, &submitter, &Badge::Veteran);
+    client.submit_proof(&gated, &submitter, &0, &proof, &Vec::new(&env));
+}
+
+#[test]
+fn test_invalid_reputation_gate_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client, token_contract, _) = setup_contract_and_token(&env);
+
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+
+    let gated = symbol_short!("GATED");
+    register_funded(&client, &env, &gated, &creator, &token_contract, &verifier);
+
+    let res = client.try_set_reputation_gate(&gated, &creator, &6, &vec![&env]);
+    assert_eq!(res, Err(Ok(Error::InvalidLevel)));
+
+    // The bar cannot move once hunters have submitted
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&gated, &Address::generate(&env), &0, &proof, &Vec::new(&env));
+    let res = client.try_set_reputation_gate(&gated, &creator, &2, &vec![&env]);
+    assert_eq!(res, Err(Ok(Error::QuestTermsLocked)));
+}
@@ -1,11 +1,15 @@
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env, symbol_short, BytesN};
+use soroban_sdk::{testutils::{Address as _, Events, Ledger}, Address, BytesN, Env, IntoVal, Vec, symbol_short};
 use soroban_sdk::token::{StellarAssetClient, TokenClient};
 
 extern crate earn_quest;
+use earn_quest::errors::Error;
 use earn_quest::{EarnQuestContract, EarnQuestContractClient};
 
+mod common;
+use common::allow_asset;
+
 fn setup_contract(env: &Env) -> (Address, EarnQuestContractClient<'_>) {
     let contract_id = env.register_contract(None, EarnQuestContract);
     let client = EarnQuestContractClient::new(env, &contract_id);
@@ -30,8 +34,9 @@ fn test_pause_blocks_register_quest() {
     client.emergency_pause(&admin);
 
     let quest_id = symbol_short!("SQ1");
+    allow_asset(&env, &client, &token_contract);
     // This should panic because contract is paused
-    client.register_quest(&quest_id, &creator, &token_contract, &100, &verifier, &10000);
+    client.register_quest(&quest_id, &creator, &token_contract, &100, &verifier, &10000, &10, &None);
 }
 
 #[test]
@@ -96,8 +101,9 @@ fn test_multisig_approve_and_unpause_with_zero_timelock() {
     let token_contract_obj = env.register_stellar_asset_contract_v2(token_admin.clone());
     let token_contract = token_contract_obj.address();
 
+    allow_asset(&env, &client, &token_contract);
     let quest_id = symbol_short!("SQ2");
-    client.register_quest(&quest_id, &creator, &token_contract, &100, &verifier, &10000);
+    client.register_quest(&quest_id, &creator, &token_contract, &100, &verifier, &10000, &10, &None);
 }
 
 #[test]
@@ -124,3 +130,436 @@ fn test_unpause_requires_enough_approvals() {
     // Unpause should fail with InsufficientApprovals
     client.emergency_unpause(&admin1);
 }
+
+/// Register and fund a quest so the contract holds 1000 of escrowed liabilities.
+fn setup_funded_quest(
+    env: &Env,
+    client: &EarnQuestContractClient,
+    token_admin_client: &StellarAssetClient,
+    token_contract: &Address,
+) {
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+    let quest_id = symbol_short!("SQ3");
+
+    allow_asset(env, client, token_contract);
+
+    client.register_quest(&quest_id, &creator, token_contract, &100, &verifier, &10000, &10, &None);
+    token_admin_client.mint(&creator, &1000);
+    client.fund_quest(&quest_id, &creator);
+}
+
+/// The funded quest's id, as the quests an override writes off.
+fn funded_quests(env: &Env) -> Vec<soroban_sdk::Symbol> {
+    Vec::from_array(env, [symbol_short!("SQ3")])
+}
+
+#[test]
+fn test_emergency_withdraw_limited_to_surplus() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_contract = env.register_stellar_asset_contract_v2(token_admin).address();
+    let token_admin_client = StellarAssetClient::new(&env, &token_contract);
+    let token_client = TokenClient::new(&env, &token_contract);
+
+    client.initialize(&admin);
+    setup_funded_quest(&env, &client, &token_admin_client, &token_contract);
+
+    // 200 sent to the contract outside of any quest escrow
+    token_admin_client.mint(&contract_id, &200);
+
+    client.emergency_pause(&admin);
+
+    // Cannot touch escrowed funds
+    let res = client.try_emergency_withdraw(&admin, &token_contract, &admin, &201);
+    assert_eq!(res, Err(Ok(Error::ExceedsSurplus)));
+
+    // The surplus can be withdrawn
+    client.emergency_withdraw(&admin, &token_contract, &admin, &200);
+    assert_eq!(token_client.balance(&admin), 200);
+    assert_eq!(token_client.balance(&contract_id), 1000);
+}
+
+#[test]
+fn test_emergency_withdraw_below_liabilities_with_override() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client) = setup_contract(&env);
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_contract = env.register_stellar_asset_contract_v2(token_admin).address();
+    let token_admin_client = StellarAssetClient::new(&env, &token_contract);
+    let token_client = TokenClient::new(&env, &token_contract);
+
+    client.initialize(&admin1);
+    client.add_admin(&admin1, &admin2);
+    client.set_unpause_threshold(&admin1, &2u32);
+    setup_funded_quest(&env, &client, &token_admin_client, &token_contract);
+    let quests = funded_quests(&env);
+
+    client.emergency_pause(&admin1);
+
+    // One approval is not enough
+    client.emergency_approve_override(&admin1, &token_contract, &admin1, &600, &quests);
+    let res = client.try_emergency_approve_override(&admin1, &token_contract, &admin1, &600, &quests);
+    assert_eq!(res, Err(Ok(Error::AlreadyApproved)));
+    let res = client.try_emergency_withdraw(&admin1, &token_contract, &admin1, &600);
+    assert_eq!(res, Err(Ok(Error::ExceedsSurplus)));
+
+    client.emergency_approve_override(&admin2, &token_contract, &admin1, &600, &quests);
+    client.emergency_withdraw(&admin1, &token_contract, &admin1, &600);
+    assert_eq!(token_client.balance(&admin1), 600);
+    assert_eq!(token_client.balance(&contract_id), 400);
+
+    // The named quest's escrow is written off and the quest cancelled
+    assert_eq!(client.get_quest_escrow(&symbol_short!("SQ3")), 0);
+    let report = client.reconcile(&token_contract);
+    assert_eq!(report.liabilities, 0);
+    assert_eq!(report.surplus, 400);
+
+    // Escrow written off beyond the withdrawal is left as surplus
+    client.emergency_withdraw(&admin1, &token_contract, &admin1, &400);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_override_does_not_leak_into_other_quests() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let hunter = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_contract = env.register_stellar_asset_contract_v2(token_admin).address();
+    let token_admin_client = StellarAssetClient::new(&env, &token_contract);
+    let token_client = TokenClient::new(&env, &token_contract);
+    let quest_a = symbol_short!("SQ3");
+    let quest_b = symbol_short!("SQ4");
+
+    client.initialize(&admin);
+    client.set_unpause_threshold(&admin, &1u32);
+    client.set_unpause_timelock(&admin, &0u64);
+    allow_asset(&env, &client, &token_contract);
+    for quest_id in [&quest_a, &quest_b] {
+        client.register_quest(quest_id, &creator, &token_contract, &100, &verifier, &10000, &10, &None);
+    }
+    token_admin_client.mint(&creator, &2000);
+    client.fund_quest(&quest_a, &creator);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    client.submit_proof(&quest_a, &hunter, &0, &proof, &Vec::new(&env));
+    client.approve_submission(&quest_a, &hunter, &verifier);
+
+    // Quest A's escrow is withdrawn in full under an override
+    client.emergency_pause(&admin);
+    client.emergency_approve_override(&admin, &token_contract, &admin, &1000, &funded_quests(&env));
+    client.emergency_withdraw(&admin, &token_contract, &admin, &1000);
+    client.emergency_approve_unpause(&admin);
+    client.emergency_unpause(&admin);
+
+    // Quest B is funded afterwards
+    client.fund_quest(&quest_b, &creator);
+
+    // Quest A's hunter cannot be paid out of quest B's deposit
+    let res = client.try_claim_reward(&quest_a, &hunter);
+    assert_eq!(res, Err(Ok(Error::InsufficientBalance)));
+    assert_eq!(client.get_quest_escrow(&quest_a), 0);
+    assert_eq!(client.get_quest_escrow(&quest_b), 1000);
+    assert_eq!(token_client.balance(&contract_id), 1000);
+    let report = client.reconcile(&token_contract);
+    assert_eq!(report.liabilities, 1000);
+    assert_eq!(report.surplus, 0);
+}
+
+#[test]
+fn test_override_bound_to_its_terms() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let thief = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_contract = env.register_stellar_asset_contract_v2(token_admin).address();
+    let token_admin_client = StellarAssetClient::new(&env, &token_contract);
+
+    client.initialize(&admin1);
+    client.add_admin(&admin1, &admin2);
+    client.set_unpause_threshold(&admin1, &2u32);
+    setup_funded_quest(&env, &client, &token_admin_client, &token_contract);
+    let quests = funded_quests(&env);
+    client.emergency_pause(&admin1);
+
+    // Only existing quests can be written off
+    let unknown = Vec::from_array(&env, [symbol_short!("NOPE")]);
+    let res = client.try_emergency_approve_override(&admin1, &token_contract, &admin1, &600, &unknown);
+    assert_eq!(res, Err(Ok(Error::QuestNotFound)));
+
+    client.emergency_approve_override(&admin1, &token_contract, &admin1, &600, &quests);
+    client.emergency_approve_override(&admin2, &token_contract, &admin1, &600, &quests);
+
+    // Approvals cannot be spent on another recipient or a larger amount
+    let res = client.try_emergency_withdraw(&admin1, &token_contract, &thief, &600);
+    assert_eq!(res, Err(Ok(Error::ExceedsSurplus)));
+    let res = client.try_emergency_withdraw(&admin1, &token_contract, &admin1, &1000);
+    assert_eq!(res, Err(Ok(Error::ExceedsSurplus)));
+
+    // Nor once they have expired
+    env.ledger().with_mut(|li| li.timestamp += earn_quest::validation::OVERRIDE_APPROVAL_SECONDS);
+    let res = client.try_emergency_withdraw(&admin1, &token_contract, &admin1, &600);
+    assert_eq!(res, Err(Ok(Error::ExceedsSurplus)));
+}
+
+#[test]
+fn test_removed_admin_approval_dropped() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let token_contract = env.register_stellar_asset_contract_v2(token_admin).address();
+    let token_admin_client = StellarAssetClient::new(&env, &token_contract);
+
+    client.initialize(&admin1);
+    client.add_admin(&admin1, &admin2);
+    client.set_unpause_threshold(&admin1, &2u32);
+    setup_funded_quest(&env, &client, &token_admin_client, &token_contract);
+    let quests = funded_quests(&env);
+
+    client.emergency_approve_override(&admin2, &token_contract, &admin1, &600, &quests);
+    client.remove_admin(&admin1, &admin2);
+    client.emergency_pause(&admin1);
+    client.emergency_approve_override(&admin1, &token_contract, &admin1, &600, &quests);
+
+    let res = client.try_emergency_withdraw(&admin1, &token_contract, &admin1, &600);
+    assert_eq!(res, Err(Ok(Error::ExceedsSurplus)));
+}
+
+#[test]
+fn test_non_admin_cannot_approve_override() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    let res = client.try_emergency_approve_override(&stranger, &stranger, &stranger, &100, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+}
+
+//================================================================================
+// Payout circuit breaker
+//================================================================================
+
+/// Fund a 100-per-claim quest capped at 250 per hour and approve `N` hunters.
+fn setup_limited_quest<const N: usize>(
+    env: &Env,
+    client: &EarnQuestContractClient,
+    admin: &Address,
+) -> (Address, [Address; N]) {
+    let creator = Address::generate(env);
+    let verifier = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let token_contract = env.register_stellar_asset_contract_v2(token_admin).address();
+    let quest_id = symbol_short!("CB1");
+
+    client.initialize(admin);
+    client.allow_asset(admin, &token_contract, &1, &100);
+    client.set_payout_limit(admin, &token_contract, &250, &3600);
+
+    client.register_quest(&quest_id, &creator, &token_contract, &100, &verifier, &100000, &(N as u32), &None);
+    StellarAssetClient::new(env, &token_contract).mint(&creator, &(100 * N as i128));
+    client.fund_quest(&quest_id, &creator);
+
+    let hunters: [Address; N] = core::array::from_fn(|_| Address::generate(env));
+    let proof = BytesN::from_array(env, &[1u8; 32]);
+    for hunter in hunters.iter() {
+        client.submit_proof(&quest_id, hunter, &0, &proof, &Vec::new(env));
+        client.approve_submission(&quest_id, hunter, &verifier);
+    }
+    (token_contract, hunters)
+}
+
+#[test]
+fn test_claim_over_window_limit_trips_breaker() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let (token_contract, hunters) = setup_limited_quest::<4>(&env, &client, &admin);
+    let token_client = TokenClient::new(&env, &token_contract);
+    let quest_id = symbol_short!("CB1");
+
+    client.claim_reward(&quest_id, &hunters[0]);
+    client.claim_reward(&quest_id, &hunters[1]);
+    assert_eq!(client.get_window_volume(&token_contract), 200);
+
+    // The third claim would reach 300 > 250: it trips the breaker and pays nothing
+    client.claim_reward(&quest_id, &hunters[2]);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    let topic: soroban_sdk::Symbol = topics.get(0).unwrap().into_val(&env);
+    assert_eq!(topic, symbol_short!("cb_trip"));
+    let (attempted, paid, max): (i128, i128, i128) = data.into_val(&env);
+    assert_eq!((attempted, paid, max), (100, 200, 250));
+    assert!(client.is_payouts_paused(&token_contract));
+    assert_eq!(token_client.balance(&hunters[2]), 0);
+    assert_eq!(client.get_quest_escrow(&quest_id), 200);
+
+    // The pause outlasts the window and holds back every claim of the asset
+    env.ledger().with_mut(|li| li.timestamp += 7200);
+    let res = client.try_claim_reward(&quest_id, &hunters[2]);
+    assert_eq!(res, Err(Ok(Error::PayoutsPaused)));
+    let res = client.try_claim_reward(&quest_id, &hunters[3]);
+    assert_eq!(res, Err(Ok(Error::PayoutsPaused)));
+
+    // The rest of the contract keeps working
+    let creator = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    client.register_quest(&symbol_short!("CB2"), &creator, &token_contract, &100, &verifier, &100000, &10, &None);
+}
+
+#[test]
+fn test_window_rolls_over() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let (token_contract, hunters) = setup_limited_quest::<3>(&env, &client, &admin);
+    let token_client = TokenClient::new(&env, &token_contract);
+    let quest_id = symbol_short!("CB1");
+
+    client.claim_reward(&quest_id, &hunters[0]);
+    env.ledger().with_mut(|li| li.timestamp += 1800);
+    client.claim_reward(&quest_id, &hunters[1]);
+
+    // A payout still counts for a full window after it was made
+    env.ledger().with_mut(|li| li.timestamp += 1800);
+    assert_eq!(client.get_window_volume(&token_contract), 200);
+
+    // The first payout leaves once its bucket is a window old; only the second still counts
+    env.ledger().with_mut(|li| li.timestamp += 3600 / 24);
+    assert_eq!(client.get_window_volume(&token_contract), 100);
+
+    client.claim_reward(&quest_id, &hunters[2]);
+    assert_eq!(token_client.balance(&hunters[2]), 100);
+    assert!(!client.is_payouts_paused(&token_contract));
+}
+
+#[test]
+fn test_resume_payouts_through_unpause_approvals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin1 = Address::generate(&env);
+    let admin2 = Address::generate(&env);
+    let (token_contract, hunters) = setup_limited_quest::<3>(&env, &client, &admin1);
+    let token_client = TokenClient::new(&env, &token_contract);
+    let quest_id = symbol_short!("CB1");
+    client.add_admin(&admin1, &admin2);
+    client.set_unpause_timelock(&admin1, &0u64);
+
+    client.claim_reward(&quest_id, &hunters[0]);
+    client.claim_reward(&quest_id, &hunters[1]);
+    client.claim_reward(&quest_id, &hunters[2]);
+    assert!(client.is_payouts_paused(&token_contract));
+
+    // Removing the limit does not lift the pause
+    client.set_payout_limit(&admin1, &token_contract, &0, &0);
+    assert!(client.is_payouts_paused(&token_contract));
+
+    // Resuming needs the unpause approvals, not a single admin
+    let res = client.try_resume_payouts(&Address::generate(&env), &token_contract);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+    client.emergency_approve_unpause(&admin1);
+    let res = client.try_resume_payouts(&admin1, &token_contract);
+    assert_eq!(res, Err(Ok(Error::InsufficientApprovals)));
+
+    client.emergency_approve_unpause(&admin2);
+    client.resume_payouts(&admin1, &token_contract);
+    assert!(!client.is_payouts_paused(&token_contract));
+
+    // The held-back submission is still approved and now gets paid
+    client.claim_reward(&quest_id, &hunters[2]);
+    assert_eq!(token_client.balance(&hunters[2]), 100);
+
+    // The approvals were consumed by the resume
+    let res = client.try_emergency_unpause(&admin1);
+    assert_eq!(res, Err(Ok(Error::InsufficientApprovals)));
+}
+
+#[test]
+fn test_payout_limit_covers_largest_reward() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let (token_contract, _) = setup_limited_quest::<1>(&env, &client, &admin);
+
+    // A limit below one reward would hold back every claim
+    let res = client.try_set_payout_limit(&admin, &token_contract, &99, &3600);
+    assert_eq!(res, Err(Ok(Error::InvalidRewardAmount)));
+
+    let res = client.try_set_payout_limit(&admin, &Address::generate(&env), &1000, &3600);
+    assert_eq!(res, Err(Ok(Error::InvalidAsset)));
+}
+
+#[test]
+fn test_payout_log_bounded() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let (contract_id, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let (token_contract, hunters) = setup_limited_quest::<30>(&env, &client, &admin);
+    client.set_payout_limit(&admin, &token_contract, &10_000, &3600);
+    let quest_id = symbol_short!("CB1");
+
+    // One claim per bucket for longer than the window
+    for hunter in hunters.iter() {
+        client.claim_reward(&quest_id, hunter);
+        env.ledger().with_mut(|li| li.timestamp += 150);
+    }
+    env.ledger().with_mut(|li| li.timestamp -= 150);
+
+    let log_len = env.as_contract(&contract_id, || {
+        earn_quest::storage::get_payout_log(&env, &token_contract).len()
+    });
+    // The window's buckets plus the one rolling out of it
+    assert_eq!(log_len, earn_quest::validation::MAX_PAYOUT_BUCKETS + 1);
+    assert_eq!(client.get_window_volume(&token_contract), 2500);
+}
+
+#[test]
+fn test_non_admin_cannot_set_payout_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (_, client) = setup_contract(&env);
+    let admin = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    client.initialize(&admin);
+
+    let res = client.try_set_payout_limit(&stranger, &asset, &250, &3600);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+}
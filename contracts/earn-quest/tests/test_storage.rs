@@ -1,12 +1,15 @@
 #![cfg(test)]
 
-use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Env};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Env, Vec};
 
 extern crate earn_quest;
 use earn_quest::storage;
 use earn_quest::types::Badge;
 use earn_quest::{EarnQuestContract, EarnQuestContractClient};
 
+mod common;
+use common::allow_asset;
+
 //================================================================================
 // Test Setup
 //================================================================================
@@ -33,8 +36,9 @@ fn test_quest_storage_through_contract() {
     let token = Address::generate(&env);
     let verifier = Address::generate(&env);
 
+    allow_asset(&env, &client, &token);
     // Register quest (stores it)
-    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
+    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000, &10, &None);
 
     // Quest should exist now (tested indirectly through successful operations)
     // This validates storage is working
@@ -53,12 +57,13 @@ fn test_submission_storage_through_contract() {
     let verifier = Address::generate(&env);
     let submitter = Address::generate(&env);
 
+    allow_asset(&env, &client, &token);
     // Register quest
-    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
+    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000, &10, &None);
 
     // Submit proof (stores submission)
     let proof = BytesN::from_array(&env, &[1u8; 32]);
-    client.submit_proof(&quest_id, &submitter, &proof);
+    client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
 
     // Submission should exist (tested through approve operation)
     client.approve_submission(&quest_id, &submitter, &verifier);
@@ -92,9 +97,10 @@ fn test_storage_isolation_between_quests() {
     let token = Address::generate(&env);
     let verifier = Address::generate(&env);
 
+    allow_asset(&env, &client, &token);
     // Register two quests
-    client.register_quest(&quest_id1, &creator, &token, &1000, &verifier, &10000);
-    client.register_quest(&quest_id2, &creator, &token, &2000, &verifier, &20000);
+    client.register_quest(&quest_id1, &creator, &token, &1000, &verifier, &10000, &10, &None);
+    client.register_quest(&quest_id2, &creator, &token, &2000, &verifier, &20000, &10, &None);
 
     // Both should exist independently
     // Verified by the fact that both register successfully
@@ -114,15 +120,16 @@ fn test_storage_isolation_between_submissions() {
     let submitter1 = Address::generate(&env);
     let submitter2 = Address::generate(&env);
 
+    allow_asset(&env, &client, &token);
     // Register quest
-    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
+    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000, &10, &None);
 
     // Two different users submit
     let proof1 = BytesN::from_array(&env, &[1u8; 32]);
     let proof2 = BytesN::from_array(&env, &[2u8; 32]);
 
-    client.submit_proof(&quest_id, &submitter1, &proof1);
-    client.submit_proof(&quest_id, &submitter2, &proof2);
+    client.submit_proof(&quest_id, &submitter1, &0, &proof1, &Vec::new(&env));
+    client.submit_proof(&quest_id, &submitter2, &0, &proof2, &Vec::new(&env));
 
     // Both submissions should exist independently
     client.approve_submission(&quest_id, &submitter1, &verifier);
@@ -206,12 +213,13 @@ fn test_quest_lifecycle_with_storage_operations() {
     let verifier = Address::generate(&env);
     let submitter = Address::generate(&env);
 
+    allow_asset(&env, &client, &token);
     // 1. Register quest (set_quest)
-    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
+    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000, &10, &None);
 
     // 2. Submit proof (set_submission)
     let proof = BytesN::from_array(&env, &[1u8; 32]);
-    client.submit_proof(&quest_id, &submitter, &proof);
+    client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
 
     // 3. Approve submission (update_submission_status used internally)
     client.approve_submission(&quest_id, &submitter, &verifier);
@@ -277,32 +285,41 @@ fn test_concurrent_operations_storage() {
 
     let (_, client) = setup_contract(&env);
 
+    let token = Address::generate(&env);
+    allow_asset(&env, &client, &token);
+
     // Create multiple quests with unique IDs
     client.register_quest(
         &symbol_short!("QUEST1"),
         &Address::generate(&env),
-        &Address::generate(&env),
+        &token,
         &1000,
         &Address::generate(&env),
         &10000,
+        &10,
+        &None,
     );
 
     client.register_quest(
         &symbol_short!("QUEST2"),
         &Address::generate(&env),
-        &Address::generate(&env),
+        &token,
         &2000,
         &Address::generate(&env),
         &10000,
+        &10,
+        &None,
     );
 
     client.register_quest(
         &symbol_short!("QUEST3"),
         &Address::generate(&env),
-        &Address::generate(&env),
+        &token,
         &3000,
         &Address::generate(&env),
         &10000,
+        &10,
+        &None,
     );
 
     // All quests should be stored independently
@@ -322,12 +339,13 @@ fn test_storage_key_collision_prevention() {
     let verifier = Address::generate(&env);
     let submitter = Address::generate(&env);
 
+    allow_asset(&env, &client, &token);
     // Register quest with "TEST" id
-    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
+    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000, &10, &None);
 
     // Submit proof with "TEST" quest id and specific submitter
     let proof = BytesN::from_array(&env, &[1u8; 32]);
-    client.submit_proof(&quest_id, &submitter, &proof);
+    client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
 
     // The DataKey enum ensures no collision between:
     // - Quest(Symbol)
@@ -349,11 +367,12 @@ fn test_error_on_duplicate_quest() {
     let token = Address::generate(&env);
     let verifier = Address::generate(&env);
 
+    allow_asset(&env, &client, &token);
     // Register quest
-    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
+    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000, &10, &None);
 
     // Try to register again with same ID - should fail
-    let result = client.try_register_quest(&quest_id, &creator, &token, &2000, &verifier, &20000);
+    let result = client.try_register_quest(&quest_id, &creator, &token, &2000, &verifier, &20000, &10, &None);
 
     assert!(result.is_err());
     // Verifies has_quest() works correctly for duplicate detection
@@ -0,0 +1,249 @@
+#![cfg(test)]
+
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+extern crate earn_quest;
+use earn_quest::errors::Error;
+use earn_quest::types::{HoldingRule, TeamMember};
+
+mod common;
+use common::setup_funded_quest;
+
+//================================================================================
+// Test Setup
+//================================================================================
+
+/// A lead taking 60% with two members taking 30% and 10%.
+fn team(env: &Env, lead: &Address) -> Vec<TeamMember> {
+    vec![
+        env,
+        TeamMember { member: lead.clone(), share_bps: 6_000 },
+        TeamMember { member: Address::generate(env), share_bps: 3_000 },
+        TeamMember { member: Address::generate(env), share_bps: 1_000 },
+    ]
+}
+
+//================================================================================
+// Team Tests
+//================================================================================
+
+#[test]
+fn test_team_reward_split_by_shares() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TM1");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 2);
+    let lead = Address::generate(&env);
+    let members = team(&env, &lead);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+
+    f.client.submit_team_proof(&quest_id, &lead, &members, &0, &proof, &Vec::new(&env));
+    assert_eq!(f.client.get_team(&quest_id, &lead), members);
+    f.client.approve_submission(&quest_id, &lead, &f.verifier);
+    f.client.claim_reward(&quest_id, &lead);
+
+    // One `claimed` event per member
+    let mut claimed = 0;
+    for (_, topics, _) in env.events().all().iter() {
+        let name: Symbol = topics.get(0).unwrap().into_val(&env);
+        if name == symbol_short!("claimed") {
+            claimed += 1;
+        }
+    }
+    assert_eq!(claimed, 3);
+
+    // XP is split by share like the reward, so a team earns no more than a solo hunter
+    let expected = [(600, 60), (300, 30), (100, 10)];
+    for (entry, (amount, xp)) in members.iter().zip(expected) {
+        assert_eq!(f.token_client.balance(&entry.member), amount);
+        assert_eq!(f.client.get_user_stats(&entry.member).xp, xp);
+    }
+}
+
+#[test]
+fn test_team_proof_requires_every_member_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TM2");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 2);
+    let lead = Address::generate(&env);
+    let members = team(&env, &lead);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+
+    f.client.submit_team_proof(&quest_id, &lead, &members, &0, &proof, &Vec::new(&env));
+
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(addr, _)| addr).collect();
+    for entry in members.iter() {
+        assert!(signers.contains(&entry.member));
+    }
+}
+
+#[test]
+fn test_invalid_team_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TM3");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 2);
+    let lead = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+
+    // Shares must add up to 100%
+    let mut short = team(&env, &lead);
+    short.set(2, TeamMember { member: Address::generate(&env), share_bps: 500 });
+    let res = f.client.try_submit_team_proof(&quest_id, &lead, &short, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::InvalidShares)));
+
+    // The lead must be one of the members
+    let outsiders = team(&env, &Address::generate(&env));
+    let res = f.client.try_submit_team_proof(&quest_id, &lead, &outsiders, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::InvalidShares)));
+
+    let solo = vec![&env, TeamMember { member: lead.clone(), share_bps: 10_000 }];
+    let res = f.client.try_submit_team_proof(&quest_id, &lead, &solo, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::InvalidShares)));
+
+    let mut crowd = vec![&env, TeamMember { member: lead.clone(), share_bps: 5_000 }];
+    for _ in 0..5 {
+        crowd.push_back(TeamMember { member: Address::generate(&env), share_bps: 1_000 });
+    }
+    let res = f.client.try_submit_team_proof(&quest_id, &lead, &crowd, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::ArrayTooLong)));
+}
+
+#[test]
+fn test_team_locked_after_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TM4");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 2);
+    let lead = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+
+    f.client.submit_team_proof(&quest_id, &lead, &team(&env, &lead), &0, &proof, &Vec::new(&env));
+    f.client.approve_submission(&quest_id, &lead, &f.verifier);
+
+    let res = f.client.try_submit_team_proof(&quest_id, &lead, &team(&env, &lead), &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::InvalidStatusTransition)));
+}
+
+#[test]
+fn test_every_member_passes_quest_gates() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TM5");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 2);
+    let rules = vec![&env, HoldingRule { token: f.token.clone(), min_balance: 10 }];
+    f.client.set_holding_rules(&quest_id, &f.creator, &rules);
+
+    let lead = Address::generate(&env);
+    let members = team(&env, &lead);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    f.token_admin.mint(&lead, &10);
+
+    // The lead holding enough does not carry the rest of the team
+    let res = f.client.try_submit_team_proof(&quest_id, &lead, &members, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::NotEligible)));
+
+    for entry in members.iter().skip(1) {
+        f.token_admin.mint(&entry.member, &10);
+    }
+    f.client.submit_team_proof(&quest_id, &lead, &members, &0, &proof, &Vec::new(&env));
+    assert_eq!(f.client.get_team(&quest_id, &lead), members);
+}
+
+#[test]
+fn test_invite_only_quest_rejects_teams() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TM6");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 2);
+    let lead = Address::generate(&env);
+    let root = env.crypto().sha256(&lead.clone().to_xdr(&env)).to_bytes();
+    f.client.set_allowlist_root(&quest_id, &f.creator, &root);
+
+    // The allowlist proof only covers the lead, so the lead has to go solo
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    let res = f.client.try_submit_team_proof(&quest_id, &lead, &team(&env, &lead), &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::NotEligible)));
+    f.client.submit_proof(&quest_id, &lead, &0, &proof, &Vec::new(&env));
+}
+
+#[test]
+fn test_share_rounding_to_zero_does_not_block_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TM7");
+    let f = setup_funded_quest(&env, &quest_id, 100, 1);
+    let lead = Address::generate(&env);
+    let member = Address::generate(&env);
+
+    // The small share comes first so it doesn't absorb the rounding dust
+    let members = vec![
+        &env,
+        TeamMember { member: member.clone(), share_bps: 50 },
+        TeamMember { member: lead.clone(), share_bps: 9_950 },
+    ];
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    f.client.submit_team_proof(&quest_id, &lead, &members, &0, &proof, &Vec::new(&env));
+    f.client.approve_submission(&quest_id, &lead, &f.verifier);
+    f.client.claim_reward(&quest_id, &lead);
+
+    assert_eq!(f.token_client.balance(&member), 0);
+    assert_eq!(f.token_client.balance(&lead), 100);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 0);
+}
+
+#[test]
+fn test_member_takes_part_in_one_team_only() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("TM8");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 5);
+    let (first, second, member) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    let pair = |lead: &Address, member: &Address| {
+        vec![
+            &env,
+            TeamMember { member: lead.clone(), share_bps: 5_000 },
+            TeamMember { member: member.clone(), share_bps: 5_000 },
+        ]
+    };
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+
+    f.client.submit_team_proof(&quest_id, &first, &pair(&first, &member), &0, &proof, &Vec::new(&env));
+
+    // Neither a second team nor a solo proof can pay the member again
+    let res = f.client.try_submit_team_proof(&quest_id, &second, &pair(&second, &member), &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::NotEligible)));
+    let res = f.client.try_submit_proof(&quest_id, &member, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::NotEligible)));
+
+    // Solo submitters can't be added to a team either
+    let solo = Address::generate(&env);
+    f.client.submit_proof(&quest_id, &solo, &0, &proof, &Vec::new(&env));
+    let res = f.client.try_submit_team_proof(&quest_id, &second, &pair(&second, &solo), &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::NotEligible)));
+
+    // Replacing a pending team releases the members it drops
+    let other = Address::generate(&env);
+    f.client.submit_team_proof(&quest_id, &first, &pair(&first, &other), &0, &proof, &Vec::new(&env));
+    f.client.submit_proof(&quest_id, &member, &0, &proof, &Vec::new(&env));
+
+    f.client.approve_submission(&quest_id, &first, &f.verifier);
+    f.client.claim_reward(&quest_id, &first);
+    assert_eq!(f.token_client.balance(&other), 500);
+    let res = f.client.try_submit_proof(&quest_id, &other, &0, &proof, &Vec::new(&env));
+    assert_eq!(res, Err(Ok(Error::NotEligible)));
+}
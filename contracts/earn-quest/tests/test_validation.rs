@@ -1,12 +1,15 @@
 #![cfg(test)]
 
-use soroban_sdk::{symbol_short, testutils::Address as _, testutils::Ledger, Address, BytesN, Env};
+use soroban_sdk::{symbol_short, testutils::Address as _, testutils::Ledger, Address, BytesN, Env, Vec};
 
 extern crate earn_quest;
 use earn_quest::types::{QuestStatus, SubmissionStatus};
 use earn_quest::validation;
 use earn_quest::{EarnQuestContract, EarnQuestContractClient};
 
+mod common;
+use common::allow_asset;
+
 //================================================================================
 // Test Setup
 //================================================================================
@@ -57,6 +60,7 @@ fn test_register_quest_creator_verifier_same_address_rejected() {
     });
 
     // Creator and verifier are the same address - should fail
+    allow_asset(&env, &client, &token);
     let result = client.try_register_quest(
         &quest_id,
         &creator,
@@ -64,6 +68,8 @@ fn test_register_quest_creator_verifier_same_address_rejected() {
         &1000,
         &creator, // same as creator
         &5000,
+        &10,
+        &None,
     );
 
     assert!(result.is_err());
@@ -125,7 +131,8 @@ fn test_register_quest_zero_reward_rejected() {
         li.timestamp = 1000;
     });
 
-    let result = client.try_register_quest(&quest_id, &creator, &token, &0, &verifier, &5000);
+    allow_asset(&env, &client, &token);
+    let result = client.try_register_quest(&quest_id, &creator, &token, &0, &verifier, &5000, &10, &None);
     assert!(result.is_err());
 }
 
@@ -145,7 +152,8 @@ fn test_register_quest_negative_reward_rejected() {
         li.timestamp = 1000;
     });
 
-    let result = client.try_register_quest(&quest_id, &creator, &token, &-500, &verifier, &5000);
+    allow_asset(&env, &client, &token);
+    let result = client.try_register_quest(&quest_id, &creator, &token, &-500, &verifier, &5000, &10, &None);
     assert!(result.is_err());
 }
 
@@ -165,6 +173,7 @@ fn test_register_quest_max_reward_valid() {
         li.timestamp = 1000;
     });
 
+    allow_asset(&env, &client, &token);
     let result = client.try_register_quest(
         &quest_id,
         &creator,
@@ -172,6 +181,8 @@ fn test_register_quest_max_reward_valid() {
         &validation::MAX_REWARD_AMOUNT,
         &verifier,
         &5000,
+        &10,
+        &None,
     );
     assert!(result.is_ok());
 }
@@ -193,8 +204,9 @@ fn test_register_quest_exceeds_max_reward_rejected() {
     });
 
     let over_max = validation::MAX_REWARD_AMOUNT + 1;
+    allow_asset(&env, &client, &token);
     let result =
-        client.try_register_quest(&quest_id, &creator, &token, &over_max, &verifier, &5000);
+        client.try_register_quest(&quest_id, &creator, &token, &over_max, &verifier, &5000, &10, &None);
     assert!(result.is_err());
 }
 
@@ -253,7 +265,8 @@ fn test_register_quest_deadline_in_past_rejected() {
     });
 
     // Deadline is 1000 (in the past)
-    let result = client.try_register_quest(&quest_id, &creator, &token, &1000, &verifier, &1000);
+    allow_asset(&env, &client, &token);
+    let result = client.try_register_quest(&quest_id, &creator, &token, &1000, &verifier, &1000, &10, &None);
     assert!(result.is_err());
 }
 
@@ -273,11 +286,34 @@ fn test_register_quest_deadline_in_future_valid() {
         li.timestamp = 1000;
     });
 
+    allow_asset(&env, &client, &token);
     let result =
-        client.try_register_quest(&quest_id, &creator, &token, &500, &verifier, &10000);
+        client.try_register_quest(&quest_id, &creator, &token, &500, &verifier, &10000, &10, &None);
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_validate_deadline_extension_valid() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1000;
+    });
+
+    let result = validation::validate_deadline_extension(&env, 5000, 6000);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_validate_deadline_extension_not_later_rejected() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 1000;
+    });
+
+    let result = validation::validate_deadline_extension(&env, 5000, 5000);
+    assert!(result.is_err());
+}
+
 //================================================================================
 // Quest Expiry Validation Tests
 //================================================================================
@@ -304,6 +340,28 @@ fn test_validate_quest_expired_rejected() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_validate_claim_window_open_rejected() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5099;
+    });
+
+    let result = validation::validate_claim_window_closed(&env, 5000, 100);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_validate_claim_window_closed_valid() {
+    let env = Env::default();
+    env.ledger().with_mut(|li| {
+        li.timestamp = 5100;
+    });
+
+    let result = validation::validate_claim_window_closed(&env, 5000, 100);
+    assert!(result.is_ok());
+}
+
 //================================================================================
 // String / Symbol Length Validation Tests
 //================================================================================
@@ -415,6 +473,24 @@ fn test_quest_status_paused_to_expired_valid() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_quest_status_completed_to_expired_valid() {
+    let result = validation::validate_quest_status_transition(
+        &QuestStatus::Completed,
+        &QuestStatus::Expired,
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_quest_status_cancelled_to_expired_valid() {
+    let result = validation::validate_quest_status_transition(
+        &QuestStatus::Cancelled,
+        &QuestStatus::Expired,
+    );
+    assert!(result.is_ok());
+}
+
 #[test]
 fn test_quest_status_completed_to_active_rejected() {
     let result = validation::validate_quest_status_transition(
@@ -442,6 +518,33 @@ fn test_quest_status_completed_to_paused_rejected() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_quest_status_active_to_cancelled_valid() {
+    let result = validation::validate_quest_status_transition(
+        &QuestStatus::Active,
+        &QuestStatus::Cancelled,
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_quest_status_paused_to_cancelled_valid() {
+    let result = validation::validate_quest_status_transition(
+        &QuestStatus::Paused,
+        &QuestStatus::Cancelled,
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_quest_status_cancelled_to_active_rejected() {
+    let result = validation::validate_quest_status_transition(
+        &QuestStatus::Cancelled,
+        &QuestStatus::Active,
+    );
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_quest_status_same_to_same_rejected() {
     let result = validation::validate_quest_status_transition(
@@ -570,6 +673,24 @@ fn test_validate_quest_claims_limit_below_max_valid() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_validate_max_claims_zero_rejected() {
+    let result = validation::validate_max_claims(0);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_validate_max_claims_at_max_valid() {
+    let result = validation::validate_max_claims(validation::MAX_QUEST_CLAIMS);
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_validate_max_claims_above_max_rejected() {
+    let result = validation::validate_max_claims(validation::MAX_QUEST_CLAIMS + 1);
+    assert!(result.is_err());
+}
+
 //================================================================================
 // Integration Tests — Full Flow with Validation
 //================================================================================
@@ -592,12 +713,13 @@ fn test_full_quest_lifecycle_with_validation() {
         li.timestamp = 1000;
     });
 
+    allow_asset(&env, &client, &token);
     // 1. Register quest (all validations pass)
-    client.register_quest(&quest_id, &creator, &token, &500, &verifier, &10000);
+    client.register_quest(&quest_id, &creator, &token, &500, &verifier, &10000, &10, &None);
 
     // 2. Submit proof
     let proof = BytesN::from_array(&env, &[1u8; 32]);
-    client.submit_proof(&quest_id, &submitter, &proof);
+    client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
 
     // 3. Approve submission (validates Pending -> Approved transition)
     client.approve_submission(&quest_id, &submitter, &verifier);
@@ -619,12 +741,13 @@ fn test_duplicate_quest_registration_rejected() {
         li.timestamp = 1000;
     });
 
+    allow_asset(&env, &client, &token);
     // First registration should succeed
-    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
+    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000, &10, &None);
 
     // Second registration with same ID should fail
     let result =
-        client.try_register_quest(&quest_id, &creator, &token, &2000, &verifier, &20000);
+        client.try_register_quest(&quest_id, &creator, &token, &2000, &verifier, &20000, &10, &None);
     assert!(result.is_err());
 }
 
@@ -639,7 +762,7 @@ fn test_submit_proof_to_nonexistent_quest_rejected() {
     let submitter = Address::generate(&env);
     let proof = BytesN::from_array(&env, &[1u8; 32]);
 
-    let result = client.try_submit_proof(&quest_id, &submitter, &proof);
+    let result = client.try_submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
     assert!(result.is_err());
 }
 
@@ -661,10 +784,12 @@ fn test_approve_by_wrong_verifier_rejected() {
         li.timestamp = 1000;
     });
 
-    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000);
+    allow_asset(&env, &client, &token);
+
+    client.register_quest(&quest_id, &creator, &token, &1000, &verifier, &10000, &10, &None);
 
     let proof = BytesN::from_array(&env, &[1u8; 32]);
-    client.submit_proof(&quest_id, &submitter, &proof);
+    client.submit_proof(&quest_id, &submitter, &0, &proof, &Vec::new(&env));
 
     // Wrong verifier attempts to approve
     let result = client.try_approve_submission(&quest_id, &submitter, &wrong_verifier);
@@ -688,7 +813,8 @@ fn test_edge_case_min_reward_amount() {
     });
 
     // Minimum valid reward amount (1)
-    let result = client.try_register_quest(&quest_id, &creator, &token, &1, &verifier, &10000);
+    allow_asset(&env, &client, &token);
+    let result = client.try_register_quest(&quest_id, &creator, &token, &1, &verifier, &10000, &10, &None);
     assert!(result.is_ok());
 }
 
@@ -709,7 +835,8 @@ fn test_edge_case_deadline_just_after_now() {
     });
 
     // Deadline is just 1 second after current timestamp
-    let result = client.try_register_quest(&quest_id, &creator, &token, &100, &verifier, &1001);
+    allow_asset(&env, &client, &token);
+    let result = client.try_register_quest(&quest_id, &creator, &token, &100, &verifier, &1001, &10, &None);
     assert!(result.is_ok());
 }
 
@@ -730,6 +857,7 @@ fn test_edge_case_deadline_exactly_at_now_rejected() {
     });
 
     // Deadline equal to now should be rejected
-    let result = client.try_register_quest(&quest_id, &creator, &token, &100, &verifier, &1000);
+    allow_asset(&env, &client, &token);
+    let result = client.try_register_quest(&quest_id, &creator, &token, &100, &verifier, &1000, &10, &None);
     assert!(result.is_err());
 }
@@ -0,0 +1,218 @@
+#![cfg(test)]
+
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, Symbol, Vec,
+};
+
+extern crate earn_quest;
+use earn_quest::errors::Error;
+use earn_quest::types::VestingSchedule;
+
+mod common;
+use common::{setup_funded_quest, FundedQuest};
+
+//================================================================================
+// Test Setup
+//================================================================================
+
+/// Cliff of 100s, then 4 tranches over 400s.
+fn schedule() -> VestingSchedule {
+    VestingSchedule {
+        cliff_seconds: 100,
+        duration_seconds: 400,
+        tranches: 4,
+    }
+}
+
+fn approve_and_claim(env: &Env, f: &FundedQuest, quest_id: &Symbol, hunter: &Address) {
+    let proof = BytesN::from_array(env, &[1u8; 32]);
+    f.client.submit_proof(quest_id, hunter, &0, &proof, &Vec::new(env));
+    f.client.approve_submission(quest_id, hunter, &f.verifier);
+    f.client.claim_reward(quest_id, hunter);
+}
+
+fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+//================================================================================
+// Vesting Tests
+//================================================================================
+
+#[test]
+fn test_claim_with_vesting_locks_reward() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("VQ1");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 2);
+    f.client.set_vesting(&quest_id, &f.creator, &schedule());
+
+    set_time(&env, 1000);
+    let hunter = Address::generate(&env);
+    approve_and_claim(&env, &f, &quest_id, &hunter);
+
+    // Nothing is transferred at claim time
+    assert_eq!(f.token_client.balance(&hunter), 0);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 2000);
+
+    let position = f.client.get_vesting(&quest_id, &hunter);
+    assert_eq!(position.total_amount, 1000);
+    assert_eq!(position.withdrawn, 0);
+    assert_eq!(position.start, 1000);
+    assert_eq!(f.client.get_unvested_amount(&quest_id, &hunter), 1000);
+}
+
+#[test]
+fn test_withdraw_vested_by_tranche() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("VQ2");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 2);
+    f.client.set_vesting(&quest_id, &f.creator, &schedule());
+
+    set_time(&env, 1000);
+    let hunter = Address::generate(&env);
+    approve_and_claim(&env, &f, &quest_id, &hunter);
+
+    // Before the cliff nothing is withdrawable
+    set_time(&env, 1050);
+    assert_eq!(f.client.get_vested_amount(&quest_id, &hunter), 0);
+    let result = f.client.try_withdraw_vested(&quest_id, &hunter);
+    assert_eq!(result, Err(Ok(Error::NothingToWithdraw)));
+
+    // Two tranches elapsed
+    set_time(&env, 1250);
+    assert_eq!(f.client.withdraw_vested(&quest_id, &hunter), 500);
+    assert_eq!(f.token_client.balance(&hunter), 500);
+
+    // Same tranche, nothing new
+    let result = f.client.try_withdraw_vested(&quest_id, &hunter);
+    assert_eq!(result, Err(Ok(Error::NothingToWithdraw)));
+
+    // Fully vested after the duration
+    set_time(&env, 2000);
+    assert_eq!(f.client.withdraw_vested(&quest_id, &hunter), 500);
+    assert_eq!(f.token_client.balance(&hunter), 1000);
+    assert_eq!(f.client.get_vesting(&quest_id, &hunter).withdrawn, 1000);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 1000);
+}
+
+#[test]
+fn test_uneven_duration_never_overvests() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("VQ7");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 2);
+    // 7 seconds do not split into 4 equal tranches
+    let uneven = VestingSchedule {
+        cliff_seconds: 0,
+        duration_seconds: 7,
+        tranches: 4,
+    };
+    f.client.set_vesting(&quest_id, &f.creator, &uneven);
+
+    set_time(&env, 1000);
+    let hunter = Address::generate(&env);
+    approve_and_claim(&env, &f, &quest_id, &hunter);
+
+    for (elapsed, vested) in [(1, 0), (2, 250), (4, 500), (6, 750), (7, 1000), (100, 1000)] {
+        set_time(&env, 1000 + elapsed);
+        assert_eq!(f.client.get_vested_amount(&quest_id, &hunter), vested);
+    }
+
+    assert_eq!(f.client.withdraw_vested(&quest_id, &hunter), 1000);
+    assert_eq!(f.client.get_quest_escrow(&quest_id), 1000);
+}
+
+#[test]
+fn test_cancel_keeps_vesting_rewards() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("VQ3");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 2);
+    f.client.set_vesting(&quest_id, &f.creator, &schedule());
+
+    set_time(&env, 1000);
+    let hunter = Address::generate(&env);
+    approve_and_claim(&env, &f, &quest_id, &hunter);
+
+    // Only the unclaimed slot is refunded
+    assert_eq!(f.client.cancel_quest(&quest_id, &f.creator), 1000);
+    assert_eq!(f.token_client.balance(&f.contract_id), 1000);
+
+    set_time(&env, 1400);
+    assert_eq!(f.client.withdraw_vested(&quest_id, &hunter), 1000);
+    assert_eq!(f.token_client.balance(&f.contract_id), 0);
+}
+
+#[test]
+fn test_set_vesting_rejected_after_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("VQ4");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 2);
+
+    let hunter = Address::generate(&env);
+    let proof = BytesN::from_array(&env, &[1u8; 32]);
+    f.client.submit_proof(&quest_id, &hunter, &0, &proof, &Vec::new(&env));
+    f.client.approve_submission(&quest_id, &hunter, &f.verifier);
+
+    let result = f.client.try_set_vesting(&quest_id, &f.creator, &schedule());
+    assert_eq!(result, Err(Ok(Error::QuestTermsLocked)));
+}
+
+#[test]
+fn test_set_vesting_rejects_invalid_schedule() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("VQ5");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 2);
+
+    let bad = VestingSchedule {
+        cliff_seconds: 500,
+        duration_seconds: 400,
+        tranches: 4,
+    };
+    let result = f.client.try_set_vesting(&quest_id, &f.creator, &bad);
+    assert_eq!(result, Err(Ok(Error::InvalidVestingSchedule)));
+
+    let result = f.client.try_set_vesting(&quest_id, &Address::generate(&env), &schedule());
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_remove_vesting_pays_directly() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("VQ7");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 2);
+    f.client.set_vesting(&quest_id, &f.creator, &schedule());
+    f.client.remove_vesting(&quest_id, &f.creator);
+
+    let hunter = Address::generate(&env);
+    approve_and_claim(&env, &f, &quest_id, &hunter);
+
+    assert_eq!(f.token_client.balance(&hunter), 1000);
+    assert!(f.client.try_get_vesting(&quest_id, &hunter).is_err());
+}
+
+#[test]
+fn test_get_vesting_without_position() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let quest_id = symbol_short!("VQ6");
+    let f = setup_funded_quest(&env, &quest_id, 1000, 2);
+
+    let result = f.client.try_get_vesting(&quest_id, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(Error::VestingNotFound)));
+}